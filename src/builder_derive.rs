@@ -1,12 +1,14 @@
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote, ToTokens};
-use syn::Error;
+use syn::{meta::ParseNestedMeta, parse_quote, spanned::Spanned, Error, Ident};
 
 use crate::core::{
     attr::{Attr, BoolAttr},
+    case::RenameRule,
     container::{AttrContainer, AttrField, AttrVariant, Container},
     context::Context,
-    data::{Data, Style},
+    data::{Data, Field, Style},
+    span::respan,
     symbol::Symbol,
 };
 
@@ -16,24 +18,116 @@ const NO_INTO: Symbol = Symbol("no_into");
 const PREFIX: Symbol = Symbol("prefix");
 const SUFFIX: Symbol = Symbol("suffix");
 const RENAME: Symbol = Symbol("rename");
+const DEFAULT: Symbol = Symbol("default");
+const SEPARATE: Symbol = Symbol("separate");
+const TYPESTATE: Symbol = Symbol("typestate");
+const STRIP_OPTION: Symbol = Symbol("strip_option");
+const CLEAR: Symbol = Symbol("clear");
+const EACH: Symbol = Symbol("each");
+const PATTERN: Symbol = Symbol("pattern");
+const VALIDATE: Symbol = Symbol("validate");
+const TRY_INTO: Symbol = Symbol("try_into");
+const NESTED: Symbol = Symbol("nested");
+const SETTER: Symbol = Symbol("setter");
+const ARG: Symbol = Symbol("arg");
+const WITH: Symbol = Symbol("with");
+const RENAME_ALL: Symbol = Symbol("rename_all");
+const VIS: Symbol = Symbol("vis");
+const PRIVATE: Symbol = Symbol("private");
+const DOC: Symbol = Symbol("doc");
+const SETTER_ATTR: Symbol = Symbol("setter_attr");
+const CONST: Symbol = Symbol("const");
+const GETTERS: Symbol = Symbol("getters");
+const GET: Symbol = Symbol("get");
+const COPY: Symbol = Symbol("copy");
+const MUT: Symbol = Symbol("mut");
+const CHANGE_TYPE: Symbol = Symbol("change_type");
+const NAME: Symbol = Symbol("name");
+const INTO: Symbol = Symbol("into");
+
+// where the setters live and how missing fields are handled
+enum Mode {
+    // setters on the type itself
+    Inline,
+    // setters on a `FooBuilder`, missing fields are reported by `build`
+    Separate,
+    // setters on a `FooBuilder`, missing fields are a type error
+    Typestate,
+}
+
+// how the setters take and give back whatever holds the fields
+#[derive(Clone, Copy, PartialEq, Eq, Default)]
+enum Pattern {
+    // `fn x(mut self, ..) -> Self`
+    #[default]
+    Owned,
+    // `fn x(&mut self, ..) -> &mut Self`
+    Mutable,
+    // `fn x(&self, ..) -> Self`, updating a clone
+    Immutable,
+}
+
+// a getter for a field, returning a copy rather than a reference with `copy` and with a `_mut`
+// version as well with `mut`
+#[derive(Clone, Copy, Default)]
+struct Getter {
+    copy: bool,
+    mutable: bool,
+}
 
 struct BuilderContainer {
     prefix: Option<String>,
     suffix: Option<String>,
+    mode: Mode,
+    strip_option: Option<bool>,
+    pattern: Option<Pattern>,
+    validate: Option<syn::Path>,
+    rename_all: Option<RenameRule>,
+    vis: Option<syn::Visibility>,
+    setter_attrs: Vec<syn::Meta>,
+    getters: bool,
 }
 
-struct BuilderVariant;
+struct BuilderVariant {
+    skip: bool,
+}
 
 struct BuilderField {
     skip: bool,
     no_into: bool,
+    try_into: bool,
     rename: Option<String>,
+    default: Option<(TokenStream, syn::Expr)>,
+    strip_option: Option<bool>,
+    each: Option<Ident>,
+    pattern: Option<Pattern>,
+    validate: Option<syn::Path>,
+    nested: bool,
+    // the argument type of the setter and the function turning it into the field
+    with: Option<(syn::Type, syn::Path)>,
+    vis: Option<syn::Visibility>,
+    doc: Option<String>,
+    setter_attrs: Vec<syn::Meta>,
+    // the `const` attribute itself so errors can point at it
+    constant: Option<TokenStream>,
+    get: Option<Getter>,
+    // the `change_type` attribute, also kept for errors
+    change_type: Option<TokenStream>,
 }
 
 impl AttrContainer for BuilderContainer {
     fn from_ast(cx: &Context, item: &syn::DeriveInput) -> Self {
         let mut prefix = Attr::none(cx, PREFIX);
         let mut suffix = Attr::none(cx, SUFFIX);
+        let mut separate = BoolAttr::none(cx, SEPARATE);
+        let mut typestate = BoolAttr::none(cx, TYPESTATE);
+        let mut strip_option = Attr::none(cx, STRIP_OPTION);
+        let mut pattern = Attr::none(cx, PATTERN);
+        let mut validate = Attr::none(cx, VALIDATE);
+        let mut rename_all = Attr::none(cx, RENAME_ALL);
+        let mut vis = Attr::none(cx, VIS);
+        let mut setter_attrs = Vec::new();
+        let mut getters = BoolAttr::none(cx, GETTERS);
 
         for attr in &item.attrs {
             if attr.path() != BUILDER {
@@ -69,6 +163,47 @@ impl AttrContainer for BuilderContainer {
                             expr.to_token_stream()
                         )));
                     }
+                } else if meta.path == SEPARATE {
+                    if typestate.get() {
+                        return Err(meta.error("`separate` cannot be combined with `typestate`"));
+                    }
+                    separate.set_true(&meta.path);
+                } else if meta.path == TYPESTATE {
+                    if separate.get() {
+                        return Err(meta.error("`typestate` cannot be combined with `separate`"));
+                    }
+                    typestate.set_true(&meta.path);
+                } else if meta.path == STRIP_OPTION {
+                    strip_option.set(&meta.path, parse_strip_option(&meta)?);
+                } else if meta.path == PATTERN {
+                    pattern.set(&meta.path, parse_pattern(&meta)?);
+                } else if meta.path == VALIDATE {
+                    validate.set(&meta.path, meta.value()?.parse()?);
+                } else if meta.path == VIS {
+                    vis.set(&meta.path, parse_vis(&meta)?);
+                } else if meta.path == PRIVATE {
+                    vis.set(&meta.path, syn::Visibility::Inherited);
+                } else if meta.path == SETTER_ATTR {
+                    setter_attrs.extend(parse_setter_attrs(&meta)?);
+                } else if meta.path == GETTERS {
+                    getters.set_true(&meta.path);
+                } else if meta.path == RENAME_ALL {
+                    let expr: syn::Expr = meta.value()?.parse()?;
+                    if let syn::Expr::Lit(syn::ExprLit {
+                        lit: syn::Lit::Str(s),
+                        ..
+                    }) = &expr
+                    {
+                        match RenameRule::parse(&s.value()) {
+                            Ok(rule) => rename_all.set(&meta.path, rule),
+                            Err(err) => return Err(Error::new(s.span(), err)),
+                        }
+                    } else {
+                        return Err(meta.error(format_args!(
+                            "rename_all must be a string not `{}`",
+                            expr.to_token_stream()
+                        )));
+                    }
                 } else {
                     let path = meta.path.to_token_stream().to_string();
                     return Err(meta.error(format_args!(
@@ -85,13 +220,50 @@ impl AttrContainer for BuilderContainer {
         BuilderContainer {
             prefix: prefix.get(),
             suffix: suffix.get(),
+            mode: if typestate.get() {
+                Mode::Typestate
+            } else if separate.get() {
+                Mode::Separate
+            } else {
+                Mode::Inline
+            },
+            strip_option: strip_option.get(),
+            pattern: pattern.get(),
+            validate: validate.get(),
+            rename_all: rename_all.get(),
+            vis: vis.get(),
+            setter_attrs,
+            getters: getters.get(),
         }
     }
 }
 
 impl AttrVariant for BuilderVariant {
-    fn from_ast(_cx: &Context, _variant: &syn::Variant) -> Self {
-        BuilderVariant
+    fn from_ast(cx: &Context, variant: &syn::Variant) -> Self {
+        let mut skip = BoolAttr::none(cx, SKIP);
+
+        for attr in &variant.attrs {
+            if attr.path() != BUILDER {
+                continue;
+            }
+
+            if let Err(err) = attr.parse_nested_meta(|meta| {
+                if meta.path == SKIP {
+                    skip.set_true(&meta.path);
+                } else {
+                    let path = meta.path.to_token_stream().to_string().replace(' ', "");
+                    return Err(meta.error(format_args!(
+                        "unknown builder variant attribute: `{}`",
+                        path
+                    )));
+                }
+                Ok(())
+            }) {
+                cx.syn_error(err);
+            }
+        }
+
+        BuilderVariant { skip: skip.get() }
     }
 }
 
@@ -99,7 +271,23 @@ impl AttrField for BuilderField {
     fn from_ast(cx: &Context, _index: usize, field: &syn::Field) -> Self {
         let mut skip = BoolAttr::none(cx, SKIP);
         let mut no_into = BoolAttr::none(cx, NO_INTO);
+        let mut try_into = BoolAttr::none(cx, TRY_INTO);
+        let mut nested = BoolAttr::none(cx, NESTED);
         let mut rename = Attr::none(cx, RENAME);
+        let mut default = Attr::none(cx, DEFAULT);
+        let mut strip_option = Attr::none(cx, STRIP_OPTION);
+        let mut each = Attr::none(cx, EACH);
+        let mut pattern = Attr::none(cx, PATTERN);
+        let mut validate = Attr::none(cx, VALIDATE);
+        let mut arg = Attr::none(cx, ARG);
+        let mut with = Attr::none(cx, WITH);
+        let mut vis = Attr::none(cx, VIS);
+        let mut doc = Attr::none(cx, DOC);
+        let mut constant = Attr::none(cx, CONST);
+        let mut get = Attr::none(cx, GET);
+        let mut change_type = Attr::none(cx, CHANGE_TYPE);
+        let mut into = Attr::none(cx, INTO);
+        let mut setter_attrs = Vec::new();
 
         for attr in &field.attrs {
             if attr.path() != BUILDER {
@@ -111,20 +299,89 @@ impl AttrField for BuilderField {
                     skip.set_true(&meta.path);
                 } else if meta.path == NO_INTO {
                     no_into.set_true(&meta.path);
+                } else if meta.path == TRY_INTO {
+                    try_into.set_true(&meta.path);
+                } else if meta.path == NESTED {
+                    nested.set_true(&meta.path);
                 } else if meta.path == RENAME {
-                    let expr: syn::Expr = meta.value()?.parse()?;
-                    if let syn::Expr::Lit(syn::ExprLit {
-                        lit: syn::Lit::Str(s),
-                        ..
-                    }) = expr
-                    {
-                        rename.set(&meta.path, s.value());
+                    rename.set(&meta.path, parse_lit_str(&meta, RENAME)?.value());
+                } else if meta.path == DEFAULT {
+                    if meta.input.peek(syn::Token![=]) {
+                        let expr: syn::Expr = meta.value()?.parse()?;
+                        if let syn::Expr::Lit(syn::ExprLit {
+                            lit: syn::Lit::Str(s),
+                            ..
+                        }) = expr
+                        {
+                            default.set(&meta.path, s.parse()?);
+                        } else {
+                            return Err(meta.error(format_args!(
+                                "default must be a string not `{}`",
+                                expr.to_token_stream()
+                            )));
+                        }
                     } else {
-                        return Err(meta.error(format_args!(
-                            "rename must be a string not `{}`",
-                            expr.to_token_stream()
-                        )));
+                        default.set(&meta.path, parse_quote!(::std::default::Default::default()));
                     }
+                } else if meta.path == STRIP_OPTION {
+                    strip_option.set(&meta.path, parse_strip_option(&meta)?);
+                } else if meta.path == EACH {
+                    let name = parse_lit_str(&meta, EACH)?;
+                    let ident = name.parse::<Ident>().map_err(|_| {
+                        Error::new(
+                            name.span(),
+                            format_args!("each must be a valid method name not `{}`", name.value()),
+                        )
+                    })?;
+                    each.set(&meta.path, ident);
+                } else if meta.path == PATTERN {
+                    pattern.set(&meta.path, parse_pattern(&meta)?);
+                } else if meta.path == VALIDATE {
+                    validate.set(&meta.path, meta.value()?.parse()?);
+                } else if meta.path == VIS {
+                    vis.set(&meta.path, parse_vis(&meta)?);
+                } else if meta.path == PRIVATE {
+                    vis.set(&meta.path, syn::Visibility::Inherited);
+                } else if meta.path == SETTER_ATTR {
+                    setter_attrs.extend(parse_setter_attrs(&meta)?);
+                } else if meta.path == CONST {
+                    constant.set(&meta.path, ());
+                } else if meta.path == GET {
+                    get.set(&meta.path, parse_get(&meta)?);
+                } else if meta.path == CHANGE_TYPE {
+                    change_type.set(&meta.path, ());
+                } else if meta.path == DOC {
+                    doc.set(&meta.path, parse_lit_str(&meta, DOC)?.value());
+                } else if meta.path == SETTER {
+                    // the grouped forms of the setter options, sharing their flat versions
+                    meta.parse_nested_meta(|meta| {
+                        if meta.path == NAME {
+                            rename.set(&meta.path, parse_lit_str(&meta, NAME)?.value());
+                        } else if meta.path == INTO {
+                            if meta.input.peek(syn::Token![=]) {
+                                let value: syn::LitBool = meta.value()?.parse()?;
+                                into.set(&meta.path, value.value);
+                            } else {
+                                into.set(&meta.path, true);
+                            }
+                        } else if meta.path == STRIP_OPTION {
+                            strip_option.set(&meta.path, parse_strip_option(&meta)?);
+                        } else if meta.path == VIS {
+                            vis.set(&meta.path, parse_vis(&meta)?);
+                        } else if meta.path == DOC {
+                            doc.set(&meta.path, parse_lit_str(&meta, DOC)?.value());
+                        } else if meta.path == ARG {
+                            arg.set(&meta.path, parse_lit_str(&meta, ARG)?.parse::<syn::Type>()?);
+                        } else if meta.path == WITH {
+                            with.set(&meta.path, meta.value()?.parse::<syn::Path>()?);
+                        } else {
+                            let path = meta.path.to_token_stream().to_string().replace(' ', "");
+                            return Err(
+                                meta.error(format_args!("unknown setter attribute: `{}`", path))
+                            );
+                        }
+                        Ok(())
+                    })?;
                 } else {
                     let path = meta.path.to_token_stream().to_string().replace(' ', "");
                     return Err(
@@ -137,11 +394,155 @@ impl AttrField for BuilderField {
             }
         }
 
+        // `no_into` and `setter(into = false)` are the same thing
+        let no_into = match into.get_with_tokens() {
+            Some((tokens, true)) if no_into.get() => {
+                cx.error_spanned_by(tokens, "`setter(into)` cannot be combined with `no_into`");
+                true
+            }
+            Some((_, into)) => no_into.get() || !into,
+            None => no_into.get(),
+        };
+
         BuilderField {
             skip: skip.get(),
-            no_into: no_into.get(),
+            no_into,
+            try_into: try_into.get(),
             rename: rename.get(),
+            default: default.get_with_tokens(),
+            strip_option: strip_option.get(),
+            each: each.get(),
+            pattern: pattern.get(),
+            validate: validate.get(),
+            nested: nested.get(),
+            with: match (arg.get_with_tokens(), with.get_with_tokens()) {
+                (Some((_, arg)), Some((_, with))) => Some((arg, with)),
+                (Some((tokens, _)), None) => {
+                    cx.error_spanned_by(tokens, "`arg` needs a `with` function to set the field");
+                    None
+                }
+                (None, Some((tokens, _))) => {
+                    cx.error_spanned_by(tokens, "`with` needs an `arg` type for the setter");
+                    None
+                }
+                (None, None) => None,
+            },
+            vis: vis.get(),
+            doc: doc.get(),
+            setter_attrs,
+            constant: constant.get_with_tokens().map(|(tokens, ())| tokens),
+            get: get.get(),
+            change_type: change_type.get_with_tokens().map(|(tokens, ())| tokens),
+        }
+    }
+}
+
+// `name = "..."`
+fn parse_lit_str(meta: &ParseNestedMeta, name: Symbol) -> syn::Result<syn::LitStr> {
+    let expr: syn::Expr = meta.value()?.parse()?;
+    if let syn::Expr::Lit(syn::ExprLit {
+        lit: syn::Lit::Str(s),
+        ..
+    }) = expr
+    {
+        Ok(s)
+    } else {
+        Err(meta.error(format_args!(
+            "{} must be a string not `{}`",
+            name,
+            expr.to_token_stream()
+        )))
+    }
+}
+
+// `strip_option` or `strip_option(clear)`, gives whether `clear` was present
+fn parse_strip_option(meta: &ParseNestedMeta) -> syn::Result<bool> {
+    let mut clear = false;
+    if meta.input.peek(syn::token::Paren) {
+        meta.parse_nested_meta(|meta| {
+            if meta.path == CLEAR {
+                clear = true;
+                Ok(())
+            } else {
+                let path = meta.path.to_token_stream().to_string().replace(' ', "");
+                Err(meta.error(format_args!("unknown strip_option attribute: `{}`", path)))
+            }
+        })?;
+    }
+    Ok(clear)
+}
+
+// `vis = "pub(crate)"`, an empty string is private
+fn parse_vis(meta: &ParseNestedMeta) -> syn::Result<syn::Visibility> {
+    let expr: syn::Expr = meta.value()?.parse()?;
+    if let syn::Expr::Lit(syn::ExprLit {
+        lit: syn::Lit::Str(s),
+        ..
+    }) = expr
+    {
+        s.parse()
+    } else {
+        Err(meta.error(format_args!(
+            "vis must be a string not `{}`",
+            expr.to_token_stream()
+        )))
+    }
+}
+
+// `get`, `get(copy)`, `get(mut)` or both
+fn parse_get(meta: &ParseNestedMeta) -> syn::Result<Getter> {
+    let mut getter = Getter::default();
+    if meta.input.peek(syn::token::Paren) {
+        meta.parse_nested_meta(|meta| {
+            if meta.path == COPY {
+                getter.copy = true;
+                Ok(())
+            } else if meta.path == MUT {
+                getter.mutable = true;
+                Ok(())
+            } else {
+                let path = meta.path.to_token_stream().to_string().replace(' ', "");
+                Err(meta.error(format_args!("unknown get attribute: `{}`", path)))
+            }
+        })?;
+    }
+    Ok(getter)
+}
+
+// `setter_attr(inline, must_use)`, each one is copied onto the generated methods
+fn parse_setter_attrs(meta: &ParseNestedMeta) -> syn::Result<Vec<syn::Meta>> {
+    let content;
+    syn::parenthesized!(content in meta.input);
+    let attrs =
+        content.parse_terminated(<syn::Meta as syn::parse::Parse>::parse, syn::Token![,])?;
+    Ok(attrs.into_iter().collect())
+}
+
+// `pattern = "owned"`, `pattern = "mutable"` or `pattern = "immutable"`
+fn parse_pattern(meta: &ParseNestedMeta) -> syn::Result<Pattern> {
+    let expr: syn::Expr = meta.value()?.parse()?;
+    if let syn::Expr::Lit(syn::ExprLit {
+        lit: syn::Lit::Str(s),
+        ..
+    }) = &expr
+    {
+        match s.value().as_str() {
+            "owned" => Ok(Pattern::Owned),
+            "mutable" => Ok(Pattern::Mutable),
+            "immutable" => Ok(Pattern::Immutable),
+            other => Err(Error::new(
+                s.span(),
+                format_args!(
+                    "unknown pattern `{}`, expected one of `owned`, `mutable` or `immutable`",
+                    other
+                ),
+            )),
         }
+    } else {
+        Err(meta.error(format_args!(
+            "pattern must be a string not `{}`",
+            expr.to_token_stream()
+        )))
     }
 }
 
@@ -154,80 +555,163 @@ pub(crate) fn impl_builder(ast: &syn::DeriveInput) -> syn::Result<TokenStream> {
         None => return Err(ctxt.check().unwrap_err()),
     };
 
-    ctxt.check()?;
-
-    let prefix = &cont.attrs.prefix.unwrap_or(String::new());
-    let suffix = &cont.attrs.suffix.unwrap_or(String::new());
-    let ident = &cont.ident;
-    let vis = &ast.vis;
+    // inline builders have no `build` to run the check in
+    if let (Mode::Inline, Some(validate)) = (&cont.attrs.mode, &cont.attrs.validate) {
+        ctxt.error_spanned_by(
+            validate,
+            "container `validate` is only supported with `separate` or `typestate` builders",
+        );
+    }
 
-    let (impl_generics, type_generics, where_clause) = cont.generics.split_for_impl();
+    // inline struct setters work on an existing value so there is nowhere to use a default
+    if let (Mode::Inline, Data::Struct(_, fields)) = (&cont.attrs.mode, &cont.data) {
+        for field in fields {
+            if let Some((tokens, _)) = &field.attrs.default {
+                ctxt.error_spanned_by(
+                    tokens,
+                    "`default` is only supported with `separate` or `typestate` builders",
+                );
+            }
+        }
+    }
 
-    let expanded = match cont.data {
-        Data::Struct(Style::Struct, fields) => {
-            let functions = fields.iter().filter_map(|f| {
-                if f.attrs.skip {
-                    None
-                } else {
-                    let method_name = if let Some(rename) = &f.attrs.rename {
-                        format_ident!("{}", rename)
-                    } else {
-                        format_ident!(
-                            "{}{}{}",
-                            prefix,
-                            &f.original.ident.as_ref().unwrap(),
-                            suffix
-                        )
-                    };
-                    let field_name = &f.original.ident;
-                    let field_ty = f.ty;
-                    if f.attrs.no_into {
-                        Some(quote! {
-                            #vis fn #method_name (mut self, value: #field_ty) -> Self {
-                                self.#field_name = value;
-                                self
-                            }
-                        })
-                    } else {
-                        Some(quote! {
-                            #vis fn #method_name (mut self, value: impl Into< #field_ty >) -> Self {
-                                self.#field_name = value.into();
-                                self
-                            }
-                        })
-                    }
-                }
-            });
-            quote! {
-                impl #impl_generics #ident #type_generics #where_clause {
-                    #(#functions)*
-                }
+    for field in cont.data.all_fields() {
+        // moving to the next state needs the builder by value
+        if let Mode::Typestate = cont.attrs.mode {
+            let pattern = field
+                .attrs
+                .pattern
+                .or(cont.attrs.pattern)
+                .unwrap_or_default();
+            if pattern != Pattern::Owned && !field.attrs.skip && !is_optional(field) {
+                ctxt.error_spanned_by(
+                    field.original,
+                    "required fields of a `typestate` builder only support `pattern = \"owned\"`",
+                );
+            }
+        }
+        if field.attrs.strip_option.is_some() && option_inner(field.ty).is_none() {
+            ctxt.error_spanned_by(
+                field.ty,
+                "`strip_option` requires a field of type `Option<T>`",
+            );
+        }
+        if field.attrs.try_into && field.attrs.no_into {
+            ctxt.error_spanned_by(
+                field.original,
+                "`try_into` cannot be combined with `no_into`",
+            );
+        }
+        if let (true, Some((_, with))) = (field.attrs.try_into, &field.attrs.with) {
+            ctxt.error_spanned_by(with, "`with` cannot be combined with `try_into`");
+        }
+        if let (true, Some(validate)) = (field.attrs.try_into, &field.attrs.validate) {
+            ctxt.error_spanned_by(validate, "`validate` cannot be combined with `try_into`");
+        }
+        if let (true, Some(validate)) = (field.attrs.nested, &field.attrs.validate) {
+            ctxt.error_spanned_by(validate, "`validate` cannot be combined with `nested`");
+        }
+        if let (Some(validate), Some(_)) = (&field.attrs.validate, &field.attrs.each) {
+            ctxt.error_spanned_by(validate, "`validate` cannot be combined with `each`");
+        }
+        if field.attrs.each.is_some() && collection_items(field.ty).is_none() {
+            ctxt.error_spanned_by(
+                field.ty,
+                "`each` requires a collection type like `Vec<T>` or `HashMap<K, V>`",
+            );
+        }
+    }
 
+    // the fields of a variant aren't there to be read through `&self`
+    if let Data::Enum(variants) = &cont.data {
+        if cont.attrs.getters {
+            ctxt.error_spanned_by(ast, "getters are only supported for structs");
+        }
+        for field in variants.iter().flat_map(|v| &v.fields) {
+            if field.attrs.get.is_some() {
+                ctxt.error_spanned_by(field.original, "getters are only supported for structs");
             }
         }
-        Data::Struct(Style::Newtype, _) => {
-            return Err(Error::new(
-                ast.ident.span(),
-                format_args!("deriving builder pattern not supported for newtype style structs"),
-            ))
+    }
+
+    for field in cont.data.all_fields() {
+        if let Some(constant) = &field.attrs.constant {
+            check_const(&ctxt, &cont.attrs, field, constant);
         }
+        if let Some(change_type) = &field.attrs.change_type {
+            check_change_type(&ctxt, &cont, field, change_type);
+        }
+    }
+
+    ctxt.check()?;
+
+    let ident = &cont.ident;
+    let expanded = match &cont.data {
         Data::Struct(Style::Unit, _) => {
             return Err(Error::new(
                 ast.ident.span(),
                 format_args!("deriving builder pattern not supported for unit-like structs"),
             ))
         }
-        Data::Struct(Style::Tuple, _) => {
-            return Err(Error::new(
-                ast.ident.span(),
-                format_args!("deriving builder pattern not supported for tuple structs"),
-            ))
+        Data::Struct(style, fields) => {
+            let target = Target {
+                attrs: &cont.attrs,
+                style,
+                fields,
+                builder: format_ident!("{}Builder", ident),
+                start: format_ident!("builder"),
+                constructor: quote! { #ident },
+            };
+            let getters = getters(&cont, &target)?;
+            let builder = match cont.attrs.mode {
+                Mode::Inline => inline_builder(&cont, &target),
+                Mode::Separate => {
+                    let error = builder_error(&cont);
+                    let builder = separate_builder(&cont, &target);
+                    quote! {
+                        #error
+                        #builder
+                    }
+                }
+                Mode::Typestate => typestate_builder(&cont, &target),
+            };
+            quote! {
+                #builder
+                #getters
+            }
         }
-        Data::Enum(_) => {
-            return Err(Error::new(
-                ast.ident.span(),
-                format_args!("deriving builder pattern not supported for enums"),
-            ))
+        Data::Enum(variants) => {
+            // unit variants have nothing to set
+            let builders = variants
+                .iter()
+                .filter(|v| !v.attrs.skip && !matches!(v.style, Style::Unit))
+                .map(|v| {
+                    let variant = &v.ident;
+                    let target = Target {
+                        attrs: &cont.attrs,
+                        style: &v.style,
+                        fields: &v.fields,
+                        builder: format_ident!("{}{}Builder", ident, variant),
+                        start: format_ident!(
+                            "{}",
+                            RenameRule::Snake.apply_to_variant(&variant.to_string())
+                        ),
+                        constructor: quote! { #ident::#variant },
+                    };
+                    match cont.attrs.mode {
+                        Mode::Inline => direct_builder(&cont, &target),
+                        Mode::Separate => separate_builder(&cont, &target),
+                        Mode::Typestate => typestate_builder(&cont, &target),
+                    }
+                });
+            let error = match cont.attrs.mode {
+                Mode::Separate => builder_error(&cont),
+                Mode::Inline | Mode::Typestate => TokenStream::new(),
+            };
+            quote! {
+                #error
+                #(#builders)*
+            }
         }
         Data::Union(_) => {
             return Err(Error::new(
@@ -239,3 +723,1479 @@ pub(crate) fn impl_builder(ast: &syn::DeriveInput) -> syn::Result<TokenStream> {
 
     Ok(expanded)
 }
+
+// `const fn` setters can only move the value into place so anything that calls other code, or
+// `&mut self` which needs a newer compiler, can't be used with them
+fn check_const(
+    ctxt: &Context,
+    attrs: &BuilderContainer,
+    field: &Field<BuilderField>,
+    constant: &TokenStream,
+) {
+    if !field.attrs.no_into {
+        ctxt.error_spanned_by(
+            constant,
+            "`const` setters can't use `Into`, add `no_into` to take the field type",
+        );
+    }
+    let conflicts = [
+        ("try_into", field.attrs.try_into),
+        ("validate", field.attrs.validate.is_some()),
+        ("each", field.attrs.each.is_some()),
+        ("nested", field.attrs.nested),
+        ("setter(with = ..)", field.attrs.with.is_some()),
+    ];
+    for (name, present) in conflicts {
+        if present {
+            ctxt.error_spanned_by(
+                constant,
+                format_args!("`const` cannot be combined with `{}`", name),
+            );
+        }
+    }
+    // moving to the next state drops the old builder, which isn't allowed for a generic type
+    if let Mode::Typestate = attrs.mode {
+        if !is_optional(field) {
+            ctxt.error_spanned_by(
+                constant,
+                "`const` is not supported for required fields of a `typestate` builder",
+            );
+        }
+    }
+    match field.attrs.pattern.or(attrs.pattern).unwrap_or_default() {
+        Pattern::Owned => {}
+        Pattern::Mutable => ctxt.error_spanned_by(
+            constant,
+            "`const` setters need `pattern = \"owned\"`, not `\"mutable\"`",
+        ),
+        Pattern::Immutable => ctxt.error_spanned_by(
+            constant,
+            "`const` setters need `pattern = \"owned\"`, not `\"immutable\"`",
+        ),
+    }
+}
+
+// the setter has to rebuild the whole value with the new type, so it needs a struct that is
+// consumed by the setter and a parameter that nothing else depends on
+fn check_change_type(
+    ctxt: &Context,
+    cont: &BuilderCont,
+    field: &Field<BuilderField>,
+    change_type: &TokenStream,
+) {
+    let supported = matches!(cont.data, Data::Struct(..))
+        && matches!(cont.attrs.mode, Mode::Inline | Mode::Separate);
+    if !supported {
+        ctxt.error_spanned_by(
+            change_type,
+            "`change_type` is only supported on structs with the default or `separate` builders",
+        );
+    }
+    match changed_param(cont.generics, field) {
+        Some(param) => {
+            let shared = cont
+                .data
+                .all_fields()
+                .any(|other| other.member != field.member && mentions(other.ty, param));
+            if shared {
+                ctxt.error_spanned_by(
+                    field.ty,
+                    format_args!(
+                        "`change_type` requires `{}` to only be used by this field",
+                        param
+                    ),
+                );
+            }
+        }
+        None => ctxt.error_spanned_by(
+            field.ty,
+            "`change_type` requires a field whose type is a generic parameter of the struct",
+        ),
+    }
+    let conflicts = [
+        ("no_into", field.attrs.no_into),
+        ("try_into", field.attrs.try_into),
+        ("validate", field.attrs.validate.is_some()),
+        ("each", field.attrs.each.is_some()),
+        ("nested", field.attrs.nested),
+        ("strip_option", field.attrs.strip_option.is_some()),
+        ("setter(with = ..)", field.attrs.with.is_some()),
+        ("const", field.attrs.constant.is_some()),
+    ];
+    for (name, present) in conflicts {
+        if present {
+            ctxt.error_spanned_by(
+                change_type,
+                format_args!("`change_type` cannot be combined with `{}`", name),
+            );
+        }
+    }
+    if field
+        .attrs
+        .pattern
+        .or(cont.attrs.pattern)
+        .unwrap_or_default()
+        != Pattern::Owned
+    {
+        ctxt.error_spanned_by(
+            change_type,
+            "`change_type` setters need `pattern = \"owned\"`",
+        );
+    }
+}
+
+// the generic parameter a field's type is, if it is just a parameter
+fn changed_param<'a>(
+    generics: &'a syn::Generics,
+    field: &Field<BuilderField>,
+) -> Option<&'a Ident> {
+    let syn::Type::Path(syn::TypePath { qself: None, path }) = field.ty else {
+        return None;
+    };
+    let ident = path.get_ident()?;
+    generics
+        .type_params()
+        .map(|param| &param.ident)
+        .find(|param| *param == ident)
+}
+
+// whether `ident` appears anywhere in `tokens`
+fn mentions(tokens: impl ToTokens, ident: &Ident) -> bool {
+    tokens
+        .into_token_stream()
+        .into_iter()
+        .any(|token| match token {
+            proc_macro2::TokenTree::Ident(other) => other == *ident,
+            proc_macro2::TokenTree::Group(group) => mentions(group.stream(), ident),
+            _ => false,
+        })
+}
+
+// `tokens` with every `from` swapped for `to`
+fn replace_ident(tokens: impl ToTokens, from: &Ident, to: &Ident) -> TokenStream {
+    tokens
+        .into_token_stream()
+        .into_iter()
+        .map(|token| match token {
+            proc_macro2::TokenTree::Ident(ident) if ident == *from => {
+                proc_macro2::TokenTree::Ident(to.clone())
+            }
+            proc_macro2::TokenTree::Group(group) => {
+                let mut replaced = proc_macro2::Group::new(
+                    group.delimiter(),
+                    replace_ident(group.stream(), from, to),
+                );
+                replaced.set_span(group.span());
+                proc_macro2::TokenTree::Group(replaced)
+            }
+            token => token,
+        })
+        .collect()
+}
+
+type BuilderCont<'a> = Container<'a, BuilderField, BuilderVariant, BuilderContainer>;
+
+// what a builder fills in, either a struct or a single variant of an enum
+struct Target<'a> {
+    attrs: &'a BuilderContainer,
+    style: &'a Style,
+    fields: &'a [Field<'a, BuilderField>],
+    // the generated builder type, when there is one
+    builder: Ident,
+    // the associated function on the type that creates the builder
+    start: Ident,
+    // the path used to construct the value, `Foo` or `Foo::Variant`
+    constructor: TokenStream,
+}
+
+// a generated method that replaces the value of a single field
+struct Setter {
+    name: Ident,
+    // generic parameters of the method, including the angle brackets
+    generics: TokenStream,
+    params: TokenStream,
+    // the new value of the field, built from `params`, can use `?` when there is an error type
+    value: TokenStream,
+    // the error type for setters that can fail
+    error: Option<TokenStream>,
+    // the doc attributes, only the main setter gets the docs of the field
+    doc: TokenStream,
+}
+
+// the name of a field before any prefix or suffix, tuple fields are `_0`, `_1`, .. and the field
+// of a newtype is `value`
+fn base_name(target: &Target, field: &Field<BuilderField>) -> String {
+    match &field.attrs.rename {
+        Some(rename) => rename.clone(),
+        None => field_name(target, field),
+    }
+}
+
+// the name of the field itself, ignoring `rename`
+fn field_name(target: &Target, field: &Field<BuilderField>) -> String {
+    match &field.member {
+        syn::Member::Named(ident) => ident.to_string(),
+        syn::Member::Unnamed(_) if matches!(target.style, Style::Newtype) => "value".to_string(),
+        syn::Member::Unnamed(index) => format!("_{}", index.index),
+    }
+}
+
+// the name of the main setter before `rename_all` is applied
+fn setter_name(target: &Target, field: &Field<BuilderField>) -> String {
+    if let Some(rename) = &field.attrs.rename {
+        return rename.clone();
+    }
+    let attrs = target.attrs;
+    let prefix = match (&attrs.prefix, &attrs.suffix) {
+        (Some(prefix), _) => prefix,
+        // `with_value` reads better than just `value`
+        (None, None) if matches!(target.style, Style::Newtype) => "with_",
+        (None, _) => "",
+    };
+    format!(
+        "{}{}{}",
+        prefix,
+        base_name(target, field),
+        attrs.suffix.as_deref().unwrap_or_default()
+    )
+}
+
+// a generated method name for `field`, `name` is converted with `rename_all`
+fn method_ident(target: &Target, field: &Field<BuilderField>, name: &str) -> Ident {
+    let name = match target.attrs.rename_all {
+        Some(rule) => rule.apply_to_field(name),
+        None => name.to_string(),
+    };
+    format_ident!(
+        "{}",
+        name,
+        span = match &field.original.ident {
+            Some(ident) => ident.span(),
+            None => field.ty.span(),
+        }
+    )
+}
+
+// the main setter, an explicit `rename` is used as is
+fn method_name(target: &Target, field: &Field<BuilderField>) -> Ident {
+    match &field.attrs.rename {
+        Some(rename) => format_ident!("{}", rename),
+        None => method_ident(target, field, &setter_name(target, field)),
+    }
+}
+
+// the method editing a `nested` field through a closure, `x_with`
+fn nested_name(target: &Target, field: &Field<BuilderField>) -> Option<Ident> {
+    if !field.attrs.nested {
+        return None;
+    }
+    let name = format!("{}_with", setter_name(target, field));
+    Some(method_ident(target, field, &name))
+}
+
+// the inner type of an `Option<T>` field when its setters should take `T`, along with whether a
+// `clear_` method should be generated
+fn strip_option<'a>(
+    attrs: &BuilderContainer,
+    field: &Field<'a, BuilderField>,
+) -> Option<(&'a syn::Type, bool)> {
+    let clear = field.attrs.strip_option.or(attrs.strip_option)?;
+    option_inner(field.ty).map(|inner| (inner, clear))
+}
+
+fn option_inner(ty: &syn::Type) -> Option<&syn::Type> {
+    let syn::Type::Path(syn::TypePath { qself: None, path }) = ty else {
+        return None;
+    };
+    let segment = path.segments.last()?;
+    if segment.ident != "Option" {
+        return None;
+    }
+    let syn::PathArguments::AngleBracketed(args) = &segment.arguments else {
+        return None;
+    };
+    match args.args.first() {
+        Some(syn::GenericArgument::Type(inner)) if args.args.len() == 1 => Some(inner),
+        _ => None,
+    }
+}
+
+// the parameter type a setter takes for `ty` and the expression turning `arg` into a `ty`
+fn convert(field: &Field<BuilderField>, ty: &syn::Type, arg: &Ident) -> (TokenStream, TokenStream) {
+    if field.attrs.no_into {
+        (quote! { #ty }, quote! { #arg })
+    } else {
+        (quote! { impl Into< #ty > }, quote! { #arg.into() })
+    }
+}
+
+// every method that replaces the value of `field`
+fn setters(target: &Target, field: &Field<BuilderField>) -> Vec<Setter> {
+    let stripped = strip_option(target.attrs, field);
+    let ty = stripped.map_or(field.ty, |(inner, _)| inner);
+    let member = member_name(&field.member);
+    let (param_ty, value) = match &field.attrs.with {
+        Some((arg, with)) => (quote! { #arg }, quote! { #with(value) }),
+        None => convert(field, ty, &format_ident!("value")),
+    };
+
+    // `try_into` setters are generic over what they convert from and fail with its error
+    let (generics, param_ty, value, error) = if field.attrs.try_into {
+        (
+            quote! { <__V: ::std::convert::TryInto<#ty>> },
+            quote! { __V },
+            quote! { ::std::convert::TryInto::try_into(value)? },
+            Some(quote! { <__V as ::std::convert::TryInto<#ty>>::Error }),
+        )
+    } else {
+        (TokenStream::new(), param_ty, value, None)
+    };
+    // a validated setter is `try_x` and checks the value before it is stored
+    let (name, value, error) = match &field.attrs.validate {
+        Some(validate) => {
+            let name = format!("try_{}", setter_name(target, field));
+            (
+                method_ident(target, field, &name),
+                quote! {{
+                    let value = #value;
+                    #validate(&value).map_err(::std::convert::Into::<::std::string::String>::into)?;
+                    value
+                }},
+                Some(quote! { ::std::string::String }),
+            )
+        }
+        None => (method_name(target, field), value, error),
+    };
+
+    match stripped {
+        Some((_, clear)) => {
+            let mut setters = vec![Setter {
+                name,
+                generics,
+                params: quote! { value: #param_ty },
+                value: quote! { ::std::option::Option::Some(#value) },
+                error,
+                doc: setter_docs(field, &format!("Sets `{}`.", member)),
+            }];
+            if clear {
+                setters.push(Setter {
+                    name: method_ident(
+                        target,
+                        field,
+                        &format!("clear_{}", base_name(target, field)),
+                    ),
+                    generics: TokenStream::new(),
+                    params: TokenStream::new(),
+                    value: quote! { ::std::option::Option::None },
+                    error: None,
+                    doc: plain_doc(&format!("Sets `{}` to `None`.", member)),
+                });
+            }
+            setters
+        }
+        None => vec![Setter {
+            name,
+            generics,
+            params: quote! { value: #param_ty },
+            value,
+            error,
+            doc: setter_docs(field, &format!("Sets `{}`.", member)),
+        }],
+    }
+}
+
+// a generated method that adds to a collection field rather than replacing it
+struct Pusher {
+    name: Ident,
+    params: TokenStream,
+    // an iterator of the items to add, built from `params`
+    items: TokenStream,
+    // the generated docs, the docs of the field are only for the setter
+    doc: String,
+}
+
+// the item types of a collection, one for sequences and sets and two for maps. Only the known
+// collection names count so wrappers like `Option<T>` or `Box<T>` aren't taken for one
+fn collection_items(ty: &syn::Type) -> Option<Vec<&syn::Type>> {
+    let syn::Type::Path(syn::TypePath { qself: None, path }) = ty else {
+        return None;
+    };
+    let segment = path.segments.last()?;
+    let syn::PathArguments::AngleBracketed(args) = &segment.arguments else {
+        return None;
+    };
+    let types: Vec<_> = args
+        .args
+        .iter()
+        .filter_map(|arg| match arg {
+            syn::GenericArgument::Type(ty) => Some(ty),
+            _ => None,
+        })
+        .collect();
+    let name = segment.ident.to_string();
+    let sequence = matches!(
+        name.as_str(),
+        "Vec" | "VecDeque" | "BinaryHeap" | "LinkedList"
+    );
+    if name.ends_with("Map") && types.len() >= 2 {
+        Some(types[..2].to_vec())
+    } else if (sequence || name.ends_with("Set")) && !types.is_empty() {
+        Some(types[..1].to_vec())
+    } else {
+        None
+    }
+}
+
+// the single item and bulk methods for a field with `each`
+fn pushers(target: &Target, field: &Field<BuilderField>) -> Vec<Pusher> {
+    let Some(each) = &field.attrs.each else {
+        return Vec::new();
+    };
+    let Some(items) = collection_items(field.ty) else {
+        return Vec::new();
+    };
+    let member = member_name(&field.member);
+    let push_doc = format!("Adds an item to `{}`.", member);
+    let extend_doc = format!("Adds every item to `{}`.", member);
+    let name = each.clone();
+    let extend = method_ident(
+        target,
+        field,
+        &format!("extend_{}", base_name(target, field)),
+    );
+    if let [key, value] = items[..] {
+        let (key_ty, key_value) = convert(field, key, &format_ident!("key"));
+        let (value_ty, value_value) = convert(field, value, &format_ident!("value"));
+        vec![
+            Pusher {
+                name,
+                params: quote! { key: #key_ty, value: #value_ty },
+                items: quote! { ::std::iter::once((#key_value, #value_value)) },
+                doc: push_doc,
+            },
+            Pusher {
+                name: extend,
+                params: quote! { items: impl IntoIterator<Item = (#key_ty, #value_ty)> },
+                items: quote! {
+                    items.into_iter().map(|(key, value)| (#key_value, #value_value))
+                },
+                doc: extend_doc,
+            },
+        ]
+    } else {
+        let (item_ty, item_value) = convert(field, items[0], &format_ident!("value"));
+        vec![
+            Pusher {
+                name,
+                params: quote! { value: #item_ty },
+                items: quote! { ::std::iter::once(#item_value) },
+                doc: push_doc,
+            },
+            Pusher {
+                name: extend,
+                params: quote! { items: impl IntoIterator<Item = #item_ty> },
+                items: quote! { items.into_iter().map(|value| #item_value) },
+                doc: extend_doc,
+            },
+        ]
+    }
+}
+
+// a method on whatever holds the fields, `body` is given the value being updated which is only
+// `self` when the value isn't cloned first, with an `error` the method gives back a `Result`
+fn method(
+    head: &TokenStream,
+    pattern: Pattern,
+    name: &Ident,
+    generics: &TokenStream,
+    params: &TokenStream,
+    error: Option<&TokenStream>,
+    body: impl FnOnce(&TokenStream) -> TokenStream,
+) -> TokenStream {
+    let (receiver, output, this) = match pattern {
+        Pattern::Owned => (quote! { mut self }, quote! { Self }, quote! { self }),
+        Pattern::Mutable => (quote! { &mut self }, quote! { &mut Self }, quote! { self }),
+        Pattern::Immutable => (quote! { &self }, quote! { Self }, quote! { this }),
+    };
+    let clone = match pattern {
+        Pattern::Immutable => quote! { let mut this = ::std::clone::Clone::clone(self); },
+        Pattern::Owned | Pattern::Mutable => TokenStream::new(),
+    };
+    let body = body(&this);
+    let (output, this) = match error {
+        Some(error) => (
+            quote! { ::std::result::Result<#output, #error> },
+            quote! { ::std::result::Result::Ok(#this) },
+        ),
+        None => (output, this),
+    };
+    quote! {
+        #head fn #name #generics (#receiver, #params) -> #output {
+            #clone
+            #body
+            #this
+        }
+    }
+}
+
+// everything before `fn` on a generated method
+fn method_head(
+    target: &Target,
+    vis: &syn::Visibility,
+    field: &Field<BuilderField>,
+    docs: &TokenStream,
+) -> TokenStream {
+    // the converted names are intentionally not snake case
+    let allow = target
+        .attrs
+        .rename_all
+        .map(|_| quote! { #[allow(non_snake_case)] });
+    let vis = field
+        .attrs
+        .vis
+        .as_ref()
+        .or(target.attrs.vis.as_ref())
+        .unwrap_or(vis);
+    let attrs = target
+        .attrs
+        .setter_attrs
+        .iter()
+        .chain(&field.attrs.setter_attrs);
+    let constness = field.attrs.constant.as_ref().map(|_| quote! { const });
+    quote! { #docs #allow #(#[#attrs])* #vis #constness }
+}
+
+// the docs of the main setter of a field, the other methods keep their generated docs as the
+// field's docs describe setting it
+fn setter_docs(field: &Field<BuilderField>, fallback: &str) -> TokenStream {
+    match &field.attrs.doc {
+        Some(doc) => quote! { #[doc = #doc] },
+        None => field_docs(field, fallback),
+    }
+}
+
+fn plain_doc(doc: &str) -> TokenStream {
+    quote! { #[doc = #doc] }
+}
+
+// the `///` docs of a field, or `fallback` when it has none
+fn field_docs(field: &Field<BuilderField>, fallback: &str) -> TokenStream {
+    let docs: Vec<_> = field
+        .original
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("doc"))
+        .collect();
+    if docs.is_empty() {
+        quote! { #[doc = #fallback] }
+    } else {
+        quote! { #(#docs)* }
+    }
+}
+
+fn nested_doc(field: &Field<BuilderField>) -> String {
+    format!("Edits `{}` with a closure.", member_name(&field.member))
+}
+
+// the setters and pushers for a field held at `place`, wrapped in an `Option` by builders that
+// track whether it has been set
+fn field_methods(
+    target: &Target,
+    vis: &syn::Visibility,
+    field: &Field<BuilderField>,
+    place: &TokenStream,
+    optional: bool,
+) -> Vec<TokenStream> {
+    let pattern = pattern(target, field);
+    let setters = setters(target, field).into_iter().map(|setter| {
+        let Setter {
+            name,
+            generics,
+            params,
+            value,
+            error,
+            doc,
+        } = setter;
+        method(
+            &method_head(target, vis, field, &doc),
+            pattern,
+            &name,
+            &generics,
+            &params,
+            error.as_ref(),
+            |this| {
+                let store = if optional {
+                    quote! { #this.#place = ::std::option::Option::Some(#value); }
+                } else {
+                    quote! { #this.#place = #value; }
+                };
+                // a `const` setter can't drop the old value, point at the type that needs it
+                match field.attrs.constant {
+                    Some(_) => respan(store, field.ty.span()),
+                    None => store,
+                }
+            },
+        )
+    });
+    // pushing onto an unset field starts from its default
+    let default = default_value(field);
+    let pushers = pushers(target, field).into_iter().map(|pusher| {
+        let Pusher {
+            name,
+            params,
+            items,
+            doc,
+        } = pusher;
+        method(
+            &method_head(target, vis, field, &plain_doc(&doc)),
+            pattern,
+            &name,
+            &TokenStream::new(),
+            &params,
+            None,
+            |this| {
+                let collection = if optional {
+                    quote! { #this.#place.get_or_insert_with(|| #default) }
+                } else {
+                    quote! { &mut #this.#place }
+                };
+                quote! { ::std::iter::Extend::extend(#collection, #items); }
+            },
+        )
+    });
+    // editing an unset field starts from its default too
+    let nested = nested_name(target, field).map(|name| {
+        let ty = field.ty;
+        let params = match pattern {
+            Pattern::Mutable => quote! { f: impl FnOnce(&mut #ty) },
+            Pattern::Owned | Pattern::Immutable => quote! { f: impl FnOnce(#ty) -> #ty },
+        };
+        method(
+            &method_head(target, vis, field, &plain_doc(&nested_doc(field))),
+            pattern,
+            &name,
+            &TokenStream::new(),
+            &params,
+            None,
+            |this| match (pattern, optional) {
+                (Pattern::Mutable, false) => quote! { f(&mut #this.#place); },
+                (Pattern::Mutable, true) => {
+                    quote! { f(#this.#place.get_or_insert_with(|| #default)); }
+                }
+                (Pattern::Owned | Pattern::Immutable, false) => {
+                    quote! { #this.#place = f(#this.#place); }
+                }
+                (Pattern::Owned | Pattern::Immutable, true) => quote! {
+                    let value = #this.#place.take().unwrap_or_else(|| #default);
+                    #this.#place = ::std::option::Option::Some(f(value));
+                },
+            },
+        )
+    });
+    setters.chain(pushers).chain(nested).collect()
+}
+
+// accessors on the type itself, named after the fields so they can clash with inline setters
+fn getters(cont: &BuilderCont, target: &Target) -> syn::Result<TokenStream> {
+    let ctxt = Context::new();
+    let ident = &cont.ident;
+    let vis = &cont.original.vis;
+    let (impl_generics, type_generics, where_clause) = cont.generics.split_for_impl();
+    let allow = target
+        .attrs
+        .rename_all
+        .map(|_| quote! { #[allow(non_snake_case)] });
+
+    let setter_names: Vec<Ident> = match target.attrs.mode {
+        Mode::Inline => target
+            .fields
+            .iter()
+            .filter(|f| !f.attrs.skip)
+            .flat_map(|f| {
+                let setters = setters(target, f).into_iter().map(|s| s.name);
+                let pushers = pushers(target, f).into_iter().map(|p| p.name);
+                setters.chain(pushers).chain(nested_name(target, f))
+            })
+            .collect(),
+        Mode::Separate | Mode::Typestate => Vec::new(),
+    };
+
+    let mut functions = Vec::new();
+    for field in target.fields {
+        let getter = match (field.attrs.get, target.attrs.getters) {
+            (Some(getter), _) => getter,
+            (None, true) => Getter::default(),
+            (None, false) => continue,
+        };
+        let member = &field.member;
+        let ty = field.ty;
+        let field_name = field_name(target, field);
+        let name = method_ident(target, field, &field_name);
+        let mut names = vec![name.clone()];
+
+        let docs = field_docs(field, &format!("Gets `{}`.", member_name(member)));
+        functions.push(if getter.copy {
+            quote! {
+                #docs
+                #allow
+                #vis fn #name(&self) -> #ty {
+                    self.#member
+                }
+            }
+        } else {
+            quote! {
+                #docs
+                #allow
+                #vis fn #name(&self) -> &#ty {
+                    &self.#member
+                }
+            }
+        });
+        if getter.mutable {
+            let name = method_ident(target, field, &format!("{}_mut", field_name));
+            let docs = field_docs(
+                field,
+                &format!("Gets a mutable reference to `{}`.", member_name(member)),
+            );
+            functions.push(quote! {
+                #docs
+                #allow
+                #vis fn #name(&mut self) -> &mut #ty {
+                    &mut self.#member
+                }
+            });
+            names.push(name);
+        }
+
+        for name in names {
+            if setter_names.contains(&name) {
+                ctxt.error_spanned_by(
+                    field.original,
+                    format_args!(
+                        "getter `{}` has the same name as a setter, use `prefix`, `suffix` or \
+                         `rename` to change the setter",
+                        name
+                    ),
+                );
+            }
+        }
+    }
+    ctxt.check()?;
+
+    if functions.is_empty() {
+        return Ok(TokenStream::new());
+    }
+    Ok(quote! {
+        impl #impl_generics #ident #type_generics #where_clause {
+            #(#functions)*
+        }
+    })
+}
+
+// setters directly on the type, relies on some other way of constructing the initial value
+fn inline_builder(cont: &BuilderCont, target: &Target) -> TokenStream {
+    let ident = &cont.ident;
+    let vis = &cont.original.vis;
+    let (impl_generics, type_generics, where_clause) = cont.generics.split_for_impl();
+
+    let functions = target
+        .fields
+        .iter()
+        .filter(|f| !f.attrs.skip && f.attrs.change_type.is_none())
+        .flat_map(|f| {
+            let member = &f.member;
+            field_methods(target, vis, f, &quote! { #member }, false)
+        });
+    let changes = target
+        .fields
+        .iter()
+        .filter(|f| !f.attrs.skip && f.attrs.change_type.is_some())
+        .map(|f| {
+            let assigns = target.fields.iter().map(|other| {
+                let member = &other.member;
+                if other.member == f.member {
+                    quote! { #member: value }
+                } else {
+                    quote! { #member: self.#member }
+                }
+            });
+            change_type_setter(cont, target, f, ident, quote! { #(#assigns,)* })
+        });
+
+    quote! {
+        impl #impl_generics #ident #type_generics #where_clause {
+            #(#functions)*
+            #(#changes)*
+        }
+    }
+}
+
+// a setter that gives back `holder` with the field's type parameter replaced by the type of the
+// new value, `fields` fills in the new `holder` from `self` and `value`
+fn change_type_setter(
+    cont: &BuilderCont,
+    target: &Target,
+    field: &Field<BuilderField>,
+    holder: &Ident,
+    fields: TokenStream,
+) -> TokenStream {
+    let Some(param) = changed_param(cont.generics, field) else {
+        return TokenStream::new();
+    };
+    let new_param = format_ident!("__{}", param);
+    let args = generic_args(cont.generics)
+        .into_iter()
+        .map(|arg| replace_ident(arg, param, &new_param));
+
+    // every bound on the old parameter has to hold for the new one too
+    let generics = cont.generics;
+    let bounds = generics
+        .params
+        .iter()
+        .filter_map(|param| match param {
+            syn::GenericParam::Type(param) if !param.bounds.is_empty() => {
+                let (ident, bounds) = (&param.ident, &param.bounds);
+                Some(quote! { #ident: #bounds })
+            }
+            syn::GenericParam::Lifetime(param) if !param.bounds.is_empty() => {
+                let (lifetime, bounds) = (&param.lifetime, &param.bounds);
+                Some(quote! { #lifetime: #bounds })
+            }
+            _ => None,
+        })
+        .chain(
+            generics
+                .where_clause
+                .iter()
+                .flat_map(|clause| &clause.predicates)
+                .map(ToTokens::to_token_stream),
+        )
+        .filter(|bound| mentions(bound, param))
+        .map(|bound| replace_ident(bound, param, &new_param));
+
+    let name = method_name(target, field);
+    let head = method_head(
+        target,
+        &cont.original.vis,
+        field,
+        &setter_docs(
+            field,
+            &format!("Sets `{}`, changing its type.", member_name(&field.member)),
+        ),
+    );
+    quote! {
+        #head fn #name<#new_param>(self, value: #new_param) -> #holder<#(#args),*>
+        where
+            #(#bounds,)*
+        {
+            #holder {
+                #fields
+            }
+        }
+    }
+}
+
+// a companion builder holding the fields themselves, starting from their defaults, used for enum
+// variants where there is no existing value to set fields on
+fn direct_builder(cont: &BuilderCont, target: &Target) -> TokenStream {
+    let ident = &cont.ident;
+    let vis = &cont.original.vis;
+    let Target {
+        builder,
+        start,
+        constructor,
+        fields,
+        ..
+    } = target;
+    let generics = cont.generics;
+    let (impl_generics, type_generics, where_clause) = generics.split_for_impl();
+
+    let set_fields: Vec<_> = fields.iter().filter(|f| !f.attrs.skip).collect();
+
+    let storage = set_fields.iter().map(|f| {
+        let name = storage_name(f);
+        let ty = f.ty;
+        quote! { #name: #ty }
+    });
+    let initial = set_fields.iter().map(|f| {
+        let name = storage_name(f);
+        let default = default_value(f);
+        quote! { #name: #default }
+    });
+    let functions = set_fields.iter().flat_map(|f| {
+        let name = storage_name(f);
+        field_methods(target, vis, f, &quote! { #name }, false)
+    });
+    let (receiver, read) = build_receiver(target);
+    let assigns = fields.iter().map(|f| {
+        let member = &f.member;
+        if f.attrs.skip {
+            let default = default_value(f);
+            quote! { #member: #default }
+        } else {
+            let value = read(&storage_name(f));
+            quote! { #member: #value }
+        }
+    });
+    let derives = builder_derives(target);
+    let (builder_doc, start_doc, build_doc) = builder_docs(target);
+
+    quote! {
+        #[doc = #builder_doc]
+        #derives
+        #vis struct #builder #generics #where_clause {
+            #(#storage,)*
+            __phantom: ::std::marker::PhantomData<fn() -> #ident #type_generics>,
+        }
+
+        impl #impl_generics #ident #type_generics #where_clause {
+            #[doc = #start_doc]
+            #vis fn #start() -> #builder #type_generics {
+                #builder {
+                    #(#initial,)*
+                    __phantom: ::std::marker::PhantomData,
+                }
+            }
+        }
+
+        impl #impl_generics #builder #type_generics #where_clause {
+            #(#functions)*
+
+            #[doc = #build_doc]
+            #vis fn build(#receiver) -> #ident #type_generics {
+                #constructor {
+                    #(#assigns,)*
+                }
+            }
+        }
+
+        impl #impl_generics ::std::convert::From<#builder #type_generics>
+            for #ident #type_generics #where_clause
+        {
+            fn from(builder: #builder #type_generics) -> Self {
+                builder.build()
+            }
+        }
+    }
+}
+
+// the error for `separate` builders, shared between the variants of an enum
+fn builder_error(cont: &BuilderCont) -> TokenStream {
+    let ident = &cont.ident;
+    let vis = &cont.original.vis;
+    let error = format_ident!("{}BuilderError", ident);
+
+    let error_doc = format!("An error from building a [`{}`].", ident);
+
+    // only there when something can reject the built value
+    let (invalid, invalid_fmt) = match &cont.attrs.validate {
+        Some(_) => (
+            quote! {
+                /// The built value was rejected by the validation.
+                Invalid(::std::string::String),
+            },
+            quote! {
+                #error::Invalid(reason) => {
+                    write!(f, "invalid `{}`: {}", stringify!(#ident), reason)
+                }
+            },
+        ),
+        None => (TokenStream::new(), TokenStream::new()),
+    };
+
+    quote! {
+        #[derive(Debug, Clone, PartialEq, Eq)]
+        #[doc = #error_doc]
+        #vis enum #error {
+            /// Some required fields were not set.
+            MissingFields(::std::vec::Vec<&'static str>),
+            #invalid
+        }
+
+        impl ::std::fmt::Display for #error {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                match self {
+                    #error::MissingFields(fields) => {
+                        write!(f, "missing fields for `{}`: ", stringify!(#ident))?;
+                        for (i, field) in fields.iter().enumerate() {
+                            if i > 0 {
+                                f.write_str(", ")?;
+                            }
+                            write!(f, "`{}`", field)?;
+                        }
+                        Ok(())
+                    }
+                    #invalid_fmt
+                }
+            }
+        }
+
+        impl ::std::error::Error for #error {}
+    }
+}
+
+// a companion `FooBuilder` that tracks which fields have been set
+fn separate_builder(cont: &BuilderCont, target: &Target) -> TokenStream {
+    let ident = &cont.ident;
+    let vis = &cont.original.vis;
+    let Target {
+        builder,
+        start,
+        constructor,
+        fields,
+        ..
+    } = target;
+    let error = format_ident!("{}BuilderError", ident);
+    let generics = cont.generics;
+    let (impl_generics, type_generics, where_clause) = generics.split_for_impl();
+
+    let set_fields: Vec<_> = fields.iter().filter(|f| !f.attrs.skip).collect();
+
+    let storage = set_fields.iter().map(|f| {
+        let name = storage_name(f);
+        let ty = f.ty;
+        quote! { #name: ::std::option::Option<#ty> }
+    });
+    let empty = set_fields.iter().map(|f| {
+        let name = storage_name(f);
+        quote! { #name: ::std::option::Option::None }
+    });
+    let functions = set_fields
+        .iter()
+        .filter(|f| f.attrs.change_type.is_none())
+        .flat_map(|f| {
+            let name = storage_name(f);
+            field_methods(target, vis, f, &quote! { #name }, true)
+        });
+    let changes = set_fields
+        .iter()
+        .filter(|f| f.attrs.change_type.is_some())
+        .map(|f| {
+            let assigns = set_fields.iter().map(|other| {
+                let name = storage_name(other);
+                if other.member == f.member {
+                    quote! { #name: ::std::option::Option::Some(value) }
+                } else {
+                    quote! { #name: self.#name }
+                }
+            });
+            let fields = quote! {
+                #(#assigns,)*
+                __phantom: ::std::marker::PhantomData,
+            };
+            change_type_setter(cont, target, f, builder, fields)
+        });
+    let checks = set_fields.iter().filter(|f| !is_optional(f)).map(|f| {
+        let name = storage_name(f);
+        // tuple fields are named by their setter rather than their index
+        let missing = match target.style {
+            Style::Struct => member_name(&f.member),
+            _ => base_name(target, f),
+        };
+        quote! {
+            if self.#name.is_none() {
+                missing.push(#missing);
+            }
+        }
+    });
+    let validate = target.attrs.validate.as_ref().map(|validate| {
+        quote! {
+            if let ::std::result::Result::Err(reason) = #validate(&value) {
+                return ::std::result::Result::Err(#error::Invalid(::std::convert::Into::into(reason)));
+            }
+        }
+    });
+    let (receiver, read) = build_receiver(target);
+    let assigns = fields.iter().map(|f| {
+        let member = &f.member;
+        if f.attrs.skip {
+            let default = default_value(f);
+            return quote! { #member: #default };
+        }
+        let value = read(&storage_name(f));
+        if is_optional(f) {
+            let default = default_value(f);
+            quote! { #member: #value.unwrap_or_else(|| #default) }
+        } else {
+            quote! { #member: #value.unwrap() }
+        }
+    });
+    let derives = builder_derives(target);
+    let (builder_doc, start_doc, build_doc) = builder_docs(target);
+
+    quote! {
+        #[doc = #builder_doc]
+        #derives
+        #vis struct #builder #generics #where_clause {
+            #(#storage,)*
+            __phantom: ::std::marker::PhantomData<fn() -> #ident #type_generics>,
+        }
+
+        impl #impl_generics ::std::default::Default for #builder #type_generics #where_clause {
+            fn default() -> Self {
+                #builder {
+                    #(#empty,)*
+                    __phantom: ::std::marker::PhantomData,
+                }
+            }
+        }
+
+        impl #impl_generics #ident #type_generics #where_clause {
+            #[doc = #start_doc]
+            #vis fn #start() -> #builder #type_generics {
+                ::std::default::Default::default()
+            }
+        }
+
+        impl #impl_generics #builder #type_generics #where_clause {
+            #(#functions)*
+            #(#changes)*
+
+            #[doc = #build_doc]
+            #vis fn build(#receiver) -> ::std::result::Result<#ident #type_generics, #error> {
+                let mut missing = ::std::vec::Vec::new();
+                #(#checks)*
+                if !missing.is_empty() {
+                    return ::std::result::Result::Err(#error::MissingFields(missing));
+                }
+                let value = #constructor {
+                    #(#assigns,)*
+                };
+                #validate
+                ::std::result::Result::Ok(value)
+            }
+        }
+    }
+}
+
+// a companion `FooBuilder` with a type parameter per required field, `()` while unset and `(T,)`
+// once set, `build` is only implemented when every parameter is set
+fn typestate_builder(cont: &BuilderCont, target: &Target) -> TokenStream {
+    let ident = &cont.ident;
+    let vis = &cont.original.vis;
+    let Target {
+        builder,
+        start,
+        constructor,
+        fields,
+        ..
+    } = target;
+    let generics = cont.generics;
+    let (impl_generics, type_generics, where_clause) = generics.split_for_impl();
+    let args = generic_args(generics);
+
+    let required: Vec<_> = fields
+        .iter()
+        .filter(|f| !f.attrs.skip && !is_optional(f))
+        .collect();
+    let optional: Vec<_> = fields
+        .iter()
+        .filter(|f| !f.attrs.skip && is_optional(f))
+        .collect();
+    let states: Vec<_> = (0..required.len())
+        .map(|i| format_ident!("__F{}", i))
+        .collect();
+    let unset: Vec<_> = required.iter().map(|_| quote! { () }).collect();
+    let set: Vec<_> = required
+        .iter()
+        .map(|f| {
+            let ty = f.ty;
+            quote! { (#ty,) }
+        })
+        .collect();
+
+    // the states come after the type's own parameters, which can't have defaults before them
+    let mut builder_generics = generics.clone();
+    for param in &mut builder_generics.params {
+        match param {
+            syn::GenericParam::Type(param) => {
+                param.eq_token = None;
+                param.default = None;
+            }
+            syn::GenericParam::Const(param) => {
+                param.eq_token = None;
+                param.default = None;
+            }
+            syn::GenericParam::Lifetime(_) => {}
+        }
+    }
+    builder_generics.params.extend(
+        states
+            .iter()
+            .map(|state| -> syn::GenericParam { parse_quote!(#state) }),
+    );
+
+    let required_storage = required.iter().zip(&states).map(|(f, state)| {
+        let name = storage_name(f);
+        quote! { #name: #state }
+    });
+    let optional_storage = optional.iter().map(|f| {
+        let name = storage_name(f);
+        let ty = f.ty;
+        quote! { #name: ::std::option::Option<#ty> }
+    });
+    let required_storage_names: Vec<_> = required.iter().map(|f| storage_name(f)).collect();
+    let optional_storage_names: Vec<_> = optional.iter().map(|f| storage_name(f)).collect();
+
+    let required_functions = required.iter().enumerate().map(|(i, f)| {
+        // every other field keeps whatever state it is in
+        let mut impl_generics = generics.clone();
+        impl_generics.params.extend(
+            states
+                .iter()
+                .enumerate()
+                .filter(|(j, _)| *j != i)
+                .map(|(_, state)| -> syn::GenericParam { parse_quote!(#state) }),
+        );
+        let (impl_generics, _, where_clause) = impl_generics.split_for_impl();
+        let before = states.iter().enumerate().map(|(j, state)| {
+            if i == j {
+                quote! { () }
+            } else {
+                quote! { #state }
+            }
+        });
+        let after: Vec<_> = states
+            .iter()
+            .enumerate()
+            .map(|(j, state)| {
+                if i == j {
+                    set[j].clone()
+                } else {
+                    quote! { #state }
+                }
+            })
+            .collect();
+        // a required field can't be edited before it is set so it starts from the default
+        let mut setters = setters(target, f);
+        if let Some(name) = nested_name(target, f) {
+            let ty = f.ty;
+            let default = default_value(f);
+            setters.push(Setter {
+                name,
+                generics: TokenStream::new(),
+                params: quote! { f: impl FnOnce(#ty) -> #ty },
+                value: quote! { f(#default) },
+                error: None,
+                doc: plain_doc(&nested_doc(f)),
+            });
+        }
+        let functions = setters.into_iter().map(|setter| {
+            let Setter {
+                name,
+                generics,
+                params,
+                value,
+                error,
+                doc,
+            } = setter;
+            let head = method_head(target, vis, f, &doc);
+            let rebuild = required.iter().enumerate().map(|(j, other)| {
+                let name = storage_name(other);
+                if i == j {
+                    quote! { #name: (#value,) }
+                } else {
+                    quote! { #name: self.#name }
+                }
+            });
+            let output = quote! { #builder <#(#args,)* #(#after),*> };
+            let rebuilt = quote! {
+                #builder {
+                    #(#rebuild,)*
+                    #(#optional_storage_names: self.#optional_storage_names,)*
+                    __phantom: ::std::marker::PhantomData,
+                }
+            };
+            let (output, rebuilt) = match error {
+                Some(error) => (
+                    quote! { ::std::result::Result<#output, #error> },
+                    quote! { ::std::result::Result::Ok(#rebuilt) },
+                ),
+                None => (output, rebuilt),
+            };
+            quote! {
+                #head fn #name #generics (self, #params) -> #output {
+                    #rebuilt
+                }
+            }
+        });
+
+        quote! {
+            impl #impl_generics #builder <#(#args,)* #(#before),*> #where_clause {
+                #(#functions)*
+            }
+        }
+    });
+
+    // optional fields can be set in any state
+    let optional_functions = optional.iter().flat_map(|f| {
+        let name = storage_name(f);
+        field_methods(target, vis, f, &quote! { #name }, true)
+    });
+    let (any_impl_generics, _, any_where_clause) = builder_generics.split_for_impl();
+
+    let assigns = fields.iter().map(|f| {
+        let member = &f.member;
+        let name = storage_name(f);
+        if f.attrs.skip {
+            let default = default_value(f);
+            quote! { #member: #default }
+        } else if is_optional(f) {
+            let default = default_value(f);
+            quote! { #member: self.#name.unwrap_or_else(|| #default) }
+        } else {
+            quote! { #member: self.#name.0 }
+        }
+    });
+
+    let built = quote! {
+        #constructor {
+            #(#assigns,)*
+        }
+    };
+    // without a missing fields case the check is the only way to fail
+    let (output, built) = match &target.attrs.validate {
+        Some(validate) => (
+            quote! { ::std::result::Result<#ident #type_generics, ::std::string::String> },
+            quote! {
+                let value = #built;
+                #validate(&value).map_err(::std::convert::Into::<::std::string::String>::into)?;
+                ::std::result::Result::Ok(value)
+            },
+        ),
+        None => (quote! { #ident #type_generics }, built),
+    };
+    let derives = builder_derives(target);
+    let (builder_doc, start_doc, build_doc) = builder_docs(target);
+
+    quote! {
+        #[doc = #builder_doc]
+        #derives
+        #vis struct #builder #builder_generics #where_clause {
+            #(#required_storage,)*
+            #(#optional_storage,)*
+            __phantom: ::std::marker::PhantomData<fn() -> #ident #type_generics>,
+        }
+
+        impl #impl_generics ::std::default::Default for #builder <#(#args,)* #(#unset),*> #where_clause {
+            fn default() -> Self {
+                #builder {
+                    #(#required_storage_names: (),)*
+                    #(#optional_storage_names: ::std::option::Option::None,)*
+                    __phantom: ::std::marker::PhantomData,
+                }
+            }
+        }
+
+        impl #impl_generics #ident #type_generics #where_clause {
+            #[doc = #start_doc]
+            #vis fn #start() -> #builder <#(#args,)* #(#unset),*> {
+                ::std::default::Default::default()
+            }
+        }
+
+        #(#required_functions)*
+
+        impl #any_impl_generics #builder <#(#args,)* #(#states),*> #any_where_clause {
+            #(#optional_functions)*
+        }
+
+        impl #impl_generics #builder <#(#args,)* #(#set),*> #where_clause {
+            #[doc = #build_doc]
+            #vis fn build(self) -> #output {
+                #built
+            }
+        }
+    }
+}
+
+// the generic parameters of a type as they would be passed as arguments
+fn generic_args(generics: &syn::Generics) -> Vec<TokenStream> {
+    generics
+        .params
+        .iter()
+        .map(|param| match param {
+            syn::GenericParam::Lifetime(param) => param.lifetime.to_token_stream(),
+            syn::GenericParam::Type(param) => param.ident.to_token_stream(),
+            syn::GenericParam::Const(param) => param.ident.to_token_stream(),
+        })
+        .collect()
+}
+
+// the style of the setters for a field, the field setting wins over the container one
+fn pattern(target: &Target, field: &Field<BuilderField>) -> Pattern {
+    field
+        .attrs
+        .pattern
+        .or(target.attrs.pattern)
+        .unwrap_or_default()
+}
+
+// the docs for a builder type, the function creating it and its `build`
+fn builder_docs(target: &Target) -> (String, String, String) {
+    let name = target.constructor.to_string().replace(' ', "");
+    (
+        format!("A builder for [`{}`].", name),
+        format!("Creates a builder for [`{}`].", name),
+        format!("Builds the [`{}`].", name),
+    )
+}
+
+// `immutable` setters clone the builder so it needs to be `Clone`
+fn builder_derives(target: &Target) -> TokenStream {
+    let immutable = target
+        .fields
+        .iter()
+        .any(|f| !f.attrs.skip && pattern(target, f) == Pattern::Immutable);
+    if immutable {
+        quote! { #[derive(Clone)] }
+    } else {
+        TokenStream::new()
+    }
+}
+
+// with `mutable` setters the builder is usually behind a `&mut` so `build` borrows it and clones
+// the fields out, gives the receiver for `build` and how to read a stored field
+fn build_receiver(target: &Target) -> (TokenStream, impl Fn(&Ident) -> TokenStream) {
+    let borrow = target.attrs.pattern == Some(Pattern::Mutable);
+    let receiver = if borrow {
+        quote! { &self }
+    } else {
+        quote! { self }
+    };
+    (receiver, move |name: &Ident| {
+        if borrow {
+            quote! { ::std::clone::Clone::clone(&self.#name) }
+        } else {
+            quote! { self.#name }
+        }
+    })
+}
+
+// fields that don't have to be set before building
+fn is_optional(field: &Field<BuilderField>) -> bool {
+    field.attrs.default.is_some() || field.attrs.each.is_some()
+}
+
+// the value used for a field that is never set
+fn default_value(field: &Field<BuilderField>) -> TokenStream {
+    match &field.attrs.default {
+        Some((_, default)) => default.to_token_stream(),
+        None => quote! { ::std::default::Default::default() },
+    }
+}
+
+// the name of a field inside a builder, tuple fields get `_0`, `_1`, ..
+fn storage_name(field: &Field<BuilderField>) -> Ident {
+    match &field.member {
+        syn::Member::Named(ident) => ident.clone(),
+        syn::Member::Unnamed(index) => format_ident!("_{}", index.index),
+    }
+}
+
+fn member_name(member: &syn::Member) -> String {
+    match member {
+        syn::Member::Named(ident) => ident.to_string(),
+        syn::Member::Unnamed(index) => index.index.to_string(),
+    }
+}
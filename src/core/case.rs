@@ -0,0 +1,121 @@
+use std::fmt::{self, Display};
+
+use self::RenameRule::*;
+
+// a case convention generated names can be converted to
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub enum RenameRule {
+    // `lowercase`
+    Lower,
+    // `UPPERCASE`
+    Upper,
+    // `PascalCase`
+    Pascal,
+    // `camelCase`
+    Camel,
+    // `snake_case`
+    Snake,
+    // `SCREAMING_SNAKE_CASE`
+    ScreamingSnake,
+}
+
+static RENAME_RULES: &[(&str, RenameRule)] = &[
+    ("lowercase", Lower),
+    ("UPPERCASE", Upper),
+    ("PascalCase", Pascal),
+    ("camelCase", Camel),
+    ("snake_case", Snake),
+    ("SCREAMING_SNAKE_CASE", ScreamingSnake),
+];
+
+impl RenameRule {
+    pub fn parse(rule: &str) -> Result<Self, ParseError> {
+        RENAME_RULES
+            .iter()
+            .find(|(name, _)| *name == rule)
+            .map(|(_, rule)| *rule)
+            .ok_or_else(|| ParseError {
+                unknown: rule.to_string(),
+            })
+    }
+
+    // converts a `snake_case` name like a field or method, leading underscores are kept so
+    // `_0` stays a valid identifier
+    pub fn apply_to_field(self, field: &str) -> String {
+        let name = field.trim_start_matches('_');
+        let underscores = &field[..field.len() - name.len()];
+        let converted = match self {
+            Lower | Snake => name.to_string(),
+            Upper | ScreamingSnake => name.to_ascii_uppercase(),
+            Pascal | Camel => {
+                let mut pascal = String::new();
+                let mut capitalize = true;
+                for ch in name.chars() {
+                    if ch == '_' {
+                        capitalize = true;
+                    } else if capitalize {
+                        pascal.push(ch.to_ascii_uppercase());
+                        capitalize = false;
+                    } else {
+                        pascal.push(ch);
+                    }
+                }
+                if self == Camel {
+                    lower_first(&pascal)
+                } else {
+                    pascal
+                }
+            }
+        };
+        format!("{}{}", underscores, converted)
+    }
+
+    // converts a `PascalCase` name like a variant or type
+    pub fn apply_to_variant(self, variant: &str) -> String {
+        match self {
+            Pascal => variant.to_string(),
+            Lower => variant.to_ascii_lowercase(),
+            Upper => variant.to_ascii_uppercase(),
+            Camel => lower_first(variant),
+            Snake | ScreamingSnake => {
+                let mut snake = String::new();
+                for (i, ch) in variant.char_indices() {
+                    if i > 0 && ch.is_uppercase() {
+                        snake.push('_');
+                    }
+                    snake.push(ch.to_ascii_lowercase());
+                }
+                if self == ScreamingSnake {
+                    snake.to_ascii_uppercase()
+                } else {
+                    snake
+                }
+            }
+        }
+    }
+}
+
+fn lower_first(name: &str) -> String {
+    let mut chars = name.chars();
+    match chars.next() {
+        Some(first) => first.to_ascii_lowercase().to_string() + chars.as_str(),
+        None => String::new(),
+    }
+}
+
+pub struct ParseError {
+    unknown: String,
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "unknown case `{}`, expected one of ", self.unknown)?;
+        for (i, (name, _)) in RENAME_RULES.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            write!(f, "`{}`", name)?;
+        }
+        Ok(())
+    }
+}
@@ -1,5 +1,7 @@
 pub mod attr;
+pub mod case;
 pub mod container;
 pub mod context;
 pub mod data;
+pub mod span;
 pub mod symbol;
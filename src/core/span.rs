@@ -0,0 +1,19 @@
+use proc_macro2::{Group, Span, TokenStream, TokenTree};
+
+// `quote_spanned!` leaves the spans of interpolated tokens alone, so move every token over for
+// errors about the whole statement to land on `span`
+pub fn respan(tokens: TokenStream, span: Span) -> TokenStream {
+    tokens
+        .into_iter()
+        .map(|mut token| {
+            if let TokenTree::Group(group) = &token {
+                let mut respanned = Group::new(group.delimiter(), respan(group.stream(), span));
+                respanned.set_span(span);
+                token = TokenTree::Group(respanned);
+            } else {
+                token.set_span(span);
+            }
+            token
+        })
+        .collect()
+}
@@ -1,33 +1,72 @@
-use proc_macro2::TokenStream;
+use proc_macro2::{Delimiter, Group, Span, TokenStream};
 use quote::{quote, quote_spanned, ToTokens};
-use syn::{spanned::Spanned, Error, Ident};
+use syn::{meta::ParseNestedMeta, parse_quote, spanned::Spanned, Error, Ident, Token};
 
 use crate::core::{
-    attr::BoolAttr,
+    attr::{Attr, BoolAttr},
     container::{AttrContainer, AttrField, AttrVariant, Container},
     context::Context,
-    data::{Data, Field, Style},
+    data::{Data, Field, Style, Variant},
+    span::respan,
     symbol::Symbol,
 };
 
 const FROM: Symbol = Symbol("from");
 const SKIP: Symbol = Symbol("skip");
+const FORWARD: Symbol = Symbol("forward");
+const TYPES: Symbol = Symbol("types");
+const DEFAULT: Symbol = Symbol("default");
 
-struct FromContainer;
+struct FromContainer {
+    forward: bool,
+    types: Vec<syn::Type>,
+}
 
 impl AttrContainer for FromContainer {
-    fn from_ast(_cx: &Context, _item: &syn::DeriveInput) -> Self {
-        FromContainer
+    fn from_ast(cx: &Context, item: &syn::DeriveInput) -> Self {
+        let mut forward = BoolAttr::none(cx, FORWARD);
+        let mut types = Vec::new();
+
+        for attr in &item.attrs {
+            if attr.path() != FROM {
+                continue;
+            }
+
+            if let Err(err) = attr.parse_nested_meta(|meta| {
+                if meta.path == FORWARD {
+                    forward.set_true(&meta.path);
+                } else if meta.path == TYPES {
+                    types.extend(parse_types(&meta)?);
+                } else {
+                    let path = meta.path.to_token_stream().to_string().replace(' ', "");
+                    return Err(
+                        meta.error(format_args!("unknown from container attribute: `{}`", path))
+                    );
+                }
+                Ok(())
+            }) {
+                cx.syn_error(err);
+            }
+        }
+
+        FromContainer {
+            forward: forward.get(),
+            types,
+        }
     }
 }
 
 struct FromVariant {
     skip: bool,
+    forward: bool,
+    types: Vec<syn::Type>,
 }
 
 impl AttrVariant for FromVariant {
     fn from_ast(cx: &Context, variant: &syn::Variant) -> Self {
         let mut skip = BoolAttr::none(cx, SKIP);
+        let mut forward = BoolAttr::none(cx, FORWARD);
+        let mut types = Vec::new();
 
         for attr in &variant.attrs {
             if attr.path() != FROM {
@@ -37,6 +76,10 @@ impl AttrVariant for FromVariant {
             if let Err(err) = attr.parse_nested_meta(|meta| {
                 if meta.path == SKIP {
                     skip.set_true(&meta.path);
+                } else if meta.path == FORWARD {
+                    forward.set_true(&meta.path);
+                } else if meta.path == TYPES {
+                    types.extend(parse_types(&meta)?);
                 } else {
                     let path = meta.path.to_token_stream().to_string().replace(' ', "");
                     return Err(
@@ -49,15 +92,88 @@ impl AttrVariant for FromVariant {
             }
         }
 
-        FromVariant { skip: skip.get() }
+        FromVariant {
+            skip: skip.get(),
+            forward: forward.get(),
+            types,
+        }
     }
 }
 
-struct FromField;
+// `types(u8, u16, ..)`
+fn parse_types(meta: &ParseNestedMeta) -> syn::Result<Vec<syn::Type>> {
+    let content;
+    syn::parenthesized!(content in meta.input);
+    let types = content.parse_terminated(<syn::Type as syn::parse::Parse>::parse, Token![,])?;
+    Ok(types.into_iter().collect())
+}
+
+struct FromField {
+    from: bool,
+    // also set by `skip`, any field with a default is left out of the type to convert from
+    default: Option<syn::Expr>,
+}
 
 impl AttrField for FromField {
-    fn from_ast(_cx: &Context, _index: usize, _field: &syn::Field) -> Self {
-        FromField
+    fn from_ast(cx: &Context, _index: usize, field: &syn::Field) -> Self {
+        let mut from = BoolAttr::none(cx, FROM);
+        let mut skip = BoolAttr::none(cx, SKIP);
+        let mut default = Attr::none(cx, DEFAULT);
+
+        for attr in &field.attrs {
+            if attr.path() != FROM {
+                continue;
+            }
+
+            // a bare `#[from]` marks the field to convert from
+            if let syn::Meta::Path(path) = &attr.meta {
+                from.set_true(path);
+                continue;
+            }
+
+            if let Err(err) = attr.parse_nested_meta(|meta| {
+                if meta.path == SKIP {
+                    skip.set_true(&meta.path);
+                } else if meta.path == DEFAULT {
+                    if meta.input.peek(Token![=]) {
+                        let expr: syn::Expr = meta.value()?.parse()?;
+                        if let syn::Expr::Lit(syn::ExprLit {
+                            lit: syn::Lit::Str(s),
+                            ..
+                        }) = expr
+                        {
+                            default.set(&meta.path, s.parse()?);
+                        } else {
+                            return Err(meta.error(format_args!(
+                                "default must be a string not `{}`",
+                                expr.to_token_stream()
+                            )));
+                        }
+                    } else {
+                        default.set(&meta.path, parse_quote!(::std::default::Default::default()));
+                    }
+                } else {
+                    let path = meta.path.to_token_stream().to_string().replace(' ', "");
+                    return Err(
+                        meta.error(format_args!("unknown from field attribute: `{}`", path))
+                    );
+                }
+                Ok(())
+            }) {
+                cx.syn_error(err);
+            }
+        }
+
+        let default = match default.get() {
+            Some(default) => Some(default),
+            None if skip.get() => Some(parse_quote!(::std::default::Default::default())),
+            None => None,
+        };
+
+        FromField {
+            from: from.get(),
+            default,
+        }
     }
 }
 
@@ -70,18 +186,108 @@ pub(crate) fn impl_from(ast: &syn::DeriveInput) -> syn::Result<TokenStream> {
         None => return Err(ctxt.check().unwrap_err()),
     };
 
+    match &cont.data {
+        Data::Struct(style, fields) => {
+            if cont.attrs.forward && source_count(fields) != 1 {
+                ctxt.error_spanned_by(
+                    &cont.ident,
+                    "`forward` needs a struct with a single field to convert into",
+                );
+            }
+            if cont.attrs.forward && !cont.attrs.types.is_empty() {
+                ctxt.error_spanned_by(
+                    &cont.ident,
+                    "`types` cannot be combined with `forward`, which already covers them",
+                );
+            }
+            check_from_fields(&ctxt, &cont.attrs, fields);
+
+            let (from_type, _) = gen_info(&cont.ident, style, fields);
+            let mut sources = vec![(&cont.ident, from_type.to_string(), None)];
+            for ty in &cont.attrs.types {
+                sources.push((&cont.ident, ty.to_token_stream().to_string(), Some(ty)));
+            }
+            check_duplicates(&ctxt, &sources);
+        }
+        Data::Enum(variants) => {
+            if cont.attrs.forward {
+                ctxt.error_spanned_by(
+                    &cont.ident,
+                    "`forward` goes on a single variant of an enum, not the enum itself",
+                );
+            }
+            if !cont.attrs.types.is_empty() {
+                ctxt.error_spanned_by(
+                    &cont.ident,
+                    "`types` goes on the variants of an enum, not the enum itself",
+                );
+            }
+            for variant in variants.iter().filter(|v| !v.attrs.types.is_empty()) {
+                if variant.attrs.skip {
+                    ctxt.error_spanned_by(&variant.ident, "`types` cannot be combined with `skip`");
+                } else if variant.attrs.forward {
+                    ctxt.error_spanned_by(
+                        &variant.ident,
+                        "`types` cannot be combined with `forward`, which already covers them",
+                    );
+                }
+            }
+            for field in variants.iter().flat_map(|v| &v.fields) {
+                if field.attrs.from {
+                    ctxt.error_spanned_by(
+                        field.original,
+                        "`#[from]` on a field is only supported for structs",
+                    );
+                }
+            }
+            check_forward(&ctxt, cont.generics, variants);
+            check_variant_duplicates(&ctxt, variants);
+        }
+        Data::Union(_) => {}
+    }
+
     ctxt.check()?;
     let ident = &cont.ident;
     let (impl_generics, type_generics, where_clause) = cont.generics.split_for_impl();
 
-    let expanded = match cont.data {
+    let expanded = match &cont.data {
         Data::Struct(style, fields) => {
-            let (from_type, from_body) = gen_info(ident, &style, &fields);
-            quote! {
-                impl #impl_generics From<#from_type> for #ident #type_generics #where_clause {
-                    fn from(value: #from_type) -> Self {
-                        #from_body
+            let (from_type, from_body) = gen_info(ident, style, fields);
+            if fields.iter().any(|f| f.attrs.from) {
+                let impls = fields.iter().filter(|f| f.attrs.from).map(|field| {
+                    let from_type = field.ty;
+                    let recurse = fields.iter().map(|f| {
+                        let member = &f.member;
+                        let value = if f.member == field.member {
+                            quote! { value }
+                        } else {
+                            field_default(f)
+                        };
+                        quote_spanned! {f.original.span() => #member: #value}
+                    });
+                    quote! {
+                        impl #impl_generics From<#from_type> for #ident #type_generics #where_clause {
+                            fn from(value: #from_type) -> Self {
+                                #ident { #(#recurse),* }
+                            }
+                        }
                     }
+                });
+                quote! { #(#impls)* }
+            } else if cont.attrs.forward {
+                forward_impl(&cont, &from_type, &from_body, None)
+            } else {
+                let types =
+                    cont.attrs.types.iter().map(|ty| {
+                        into_impl(&cont, cont.generics, ty, &from_type, &from_body, None)
+                    });
+                quote! {
+                    impl #impl_generics From<#from_type> for #ident #type_generics #where_clause {
+                        fn from(value: #from_type) -> Self {
+                            #from_body
+                        }
+                    }
+                    #(#types)*
                 }
             }
         }
@@ -89,17 +295,41 @@ pub(crate) fn impl_from(ast: &syn::DeriveInput) -> syn::Result<TokenStream> {
         Data::Enum(variants) => {
             let variants = variants.iter().filter_map(|v| {
                 if v.attrs.skip {
-                    None
-                } else {
+                    return None;
+                }
                 let v_name = &v.ident;
                 let (from_type, from_body) = gen_info(v_name, &v.style, &v.fields);
-                Some(quote! {
-                    impl #impl_generics From<#from_type> for #ident #type_generics #where_clause {
-                        fn from(value: #from_type) -> Self {
-                            #ident::#from_body
-                        }
-                    }
-                })
+                let from_body = quote! { #ident::#from_body };
+                // overlapping impls are reported on the variant or type they came from
+                let span = Some(v_name.span());
+                if v.attrs.forward {
+                    Some(forward_impl(&cont, &from_type, &from_body, span))
+                } else {
+                    let types = v.attrs.types.iter().map(|ty| {
+                        into_impl(
+                            &cont,
+                            cont.generics,
+                            ty,
+                            &from_type,
+                            &from_body,
+                            Some(ty.span()),
+                        )
+                    });
+                    let from = from_impl(
+                        &cont,
+                        cont.generics,
+                        &from_type,
+                        quote! {
+                            fn from(value: #from_type) -> Self {
+                                #from_body
+                            }
+                        },
+                        span,
+                    );
+                    Some(quote! {
+                        #from
+                        #(#types)*
+                    })
                 }
             });
 
@@ -117,58 +347,331 @@ pub(crate) fn impl_from(ast: &syn::DeriveInput) -> syn::Result<TokenStream> {
     Ok(expanded)
 }
 
-fn gen_info<F: AttrField>(
+// `impl<__T: Into<Type>> From<__T>`, converting into the single field first
+fn forward_impl(
+    cont: &Container<FromField, FromVariant, FromContainer>,
+    from_type: &TokenStream,
+    from_body: &TokenStream,
+    span: Option<Span>,
+) -> TokenStream {
+    let mut generics = cont.generics.clone();
+    generics.params.push(parse_quote!(__T: Into<#from_type>));
+    into_impl(cont, &generics, quote!(__T), from_type, from_body, span)
+}
+
+// `From<Source>` by going through `Into` the usual type to convert from
+fn into_impl<S: ToTokens>(
+    cont: &Container<FromField, FromVariant, FromContainer>,
+    generics: &syn::Generics,
+    source: S,
+    from_type: &TokenStream,
+    from_body: &TokenStream,
+    span: Option<Span>,
+) -> TokenStream {
+    // point at the source type when it doesn't convert
+    let convert = quote_spanned! {source.span() => let value: #from_type = value.into();};
+    let body = quote! {
+        fn from(value: #source) -> Self {
+            #convert
+            #from_body
+        }
+    };
+    from_impl(cont, generics, &source, body, span)
+}
+
+// `impl<..> From<Source> for Type<..> { .. }`, the compiler reports overlapping impls on the header
+// and braces so those can be moved onto the variant or type the impl came from. The body keeps its
+// spans so lints still see it as generated
+fn from_impl<S: ToTokens>(
+    cont: &Container<FromField, FromVariant, FromContainer>,
+    generics: &syn::Generics,
+    source: S,
+    body: TokenStream,
+    span: Option<Span>,
+) -> TokenStream {
+    let ident = &cont.ident;
+    let (impl_generics, _, _) = generics.split_for_impl();
+    let (_, type_generics, where_clause) = cont.generics.split_for_impl();
+    let header = quote! { impl #impl_generics From<#source> for #ident #type_generics };
+    let mut body = Group::new(Delimiter::Brace, body);
+    match span {
+        Some(span) => {
+            let header = respan(header, span);
+            body.set_span(span);
+            quote! { #header #where_clause #body }
+        }
+        None => quote! { #header #where_clause #body },
+    }
+}
+
+// a forwarding impl covers every type, so a second one would always overlap with it
+fn check_forward(
+    cx: &Context,
+    generics: &syn::Generics,
+    variants: &[Variant<'_, FromField, FromVariant>],
+) {
+    let mut forwarding = None;
+    for variant in variants.iter().filter(|v| v.attrs.forward) {
+        if variant.attrs.skip {
+            cx.error_spanned_by(&variant.ident, "`forward` cannot be combined with `skip`");
+        } else if source_count(&variant.fields) != 1 {
+            cx.error_spanned_by(
+                &variant.ident,
+                "`forward` needs a variant with a single field to convert into",
+            );
+        } else if let Some(first) = forwarding {
+            cx.error_spanned_by(
+                &variant.ident,
+                format_args!(
+                    "only one variant can use `forward`, `{}` already does",
+                    first
+                ),
+            );
+        } else {
+            forwarding = Some(&variant.ident);
+        }
+    }
+
+    // the other impls overlap with the forwarding one unless the compiler can see their type
+    // doesn't convert into the forwarded one, which it only can for types from this crate
+    let Some(forwarding) = forwarding else {
+        return;
+    };
+    let others = variants
+        .iter()
+        .filter(|v| !v.attrs.skip && !v.attrs.forward);
+    for variant in others {
+        let sources: Vec<_> = variant
+            .fields
+            .iter()
+            .filter(|f| f.attrs.default.is_none())
+            .collect();
+        let overlaps = match sources.as_slice() {
+            [field] => maybe_foreign(field.ty, generics),
+            // `()` and tuples are never from this crate
+            _ => true,
+        };
+        if overlaps {
+            let (from_type, _) = gen_info(&variant.ident, &variant.style, &variant.fields);
+            cx.error_spanned_by(
+                &variant.ident,
+                format_args!(
+                    "`From<{}>` can overlap with the `forward` of `{}` as the type isn't from this crate, use `#[from(skip)]` on this variant",
+                    from_type.to_string().replace(' ', ""),
+                    forwarding
+                ),
+            );
+        }
+        for ty in variant
+            .attrs
+            .types
+            .iter()
+            .filter(|ty| maybe_foreign(ty, generics))
+        {
+            cx.error_spanned_by(
+                ty,
+                format_args!(
+                    "`From<{}>` can overlap with the `forward` of `{}` as the type isn't from this crate, remove it from `types`",
+                    ty.to_token_stream().to_string().replace(' ', ""),
+                    forwarding
+                ),
+            );
+        }
+    }
+}
+
+// types that are known to come from outside of the crate, which can't be told apart from the ones
+// that are in scope under the same name
+const FOREIGN_TYPES: &[&str] = &[
+    "bool", "char", "str", "i8", "i16", "i32", "i64", "i128", "isize", "u8", "u16", "u32", "u64",
+    "u128", "usize", "f32", "f64", "String", "Vec", "Box", "Option", "Result", "Cow", "Rc", "Arc",
+    "HashMap", "HashSet", "BTreeMap", "BTreeSet", "VecDeque", "Path", "PathBuf", "Duration",
+];
+
+// whether a type might not be from this crate. Anything other than a path is, along with paths
+// into `std` and the usual `std` types, while generic parameters could be anything
+fn maybe_foreign(ty: &syn::Type, generics: &syn::Generics) -> bool {
+    match ty {
+        syn::Type::Group(group) => maybe_foreign(&group.elem, generics),
+        syn::Type::Paren(paren) => maybe_foreign(&paren.elem, generics),
+        syn::Type::Path(syn::TypePath { qself: None, path }) => {
+            let first = &path.segments[0].ident;
+            if path.segments.len() == 1 {
+                generics.type_params().any(|param| param.ident == *first)
+                    || FOREIGN_TYPES.iter().any(|name| first == name)
+            } else {
+                first == "std" || first == "core" || first == "alloc"
+            }
+        }
+        _ => true,
+    }
+}
+
+// fields marked with `#[from]` each get their own impl with the rest of the fields defaulted
+fn check_from_fields(cx: &Context, attrs: &FromContainer, fields: &[Field<'_, FromField>]) {
+    let marked: Vec<_> = fields.iter().filter(|f| f.attrs.from).collect();
+    for field in &marked {
+        if field.attrs.default.is_some() {
+            cx.error_spanned_by(
+                field.original,
+                "`skip` and `default` cannot be combined with `#[from]`",
+            );
+        }
+    }
+
+    if marked.is_empty() {
+        return;
+    }
+    if attrs.forward || !attrs.types.is_empty() {
+        cx.error_spanned_by(
+            marked[0].original,
+            "`forward` and `types` can't be combined with `#[from]` on a field",
+        );
+    }
+    for (i, field) in marked.iter().enumerate() {
+        let ty = field.ty.to_token_stream().to_string();
+        if let Some(first) = marked[..i]
+            .iter()
+            .find(|f| f.ty.to_token_stream().to_string() == ty)
+        {
+            cx.error_spanned_by(
+                field.original,
+                format_args!(
+                    "only one field of type `{}` can be marked with `#[from]`, `{}` already is",
+                    ty.replace(' ', ""),
+                    member_name(&first.member)
+                ),
+            );
+        }
+    }
+}
+
+// the fields that are converted from rather than skipped
+fn source_count(fields: &[Field<'_, FromField>]) -> usize {
+    fields.iter().filter(|f| f.attrs.default.is_none()).count()
+}
+
+fn field_default(field: &Field<'_, FromField>) -> TokenStream {
+    match &field.attrs.default {
+        Some(default) => default.to_token_stream(),
+        None => quote! { ::std::default::Default::default() },
+    }
+}
+
+fn member_name(member: &syn::Member) -> String {
+    match member {
+        syn::Member::Named(ident) => ident.to_string(),
+        syn::Member::Unnamed(index) => index.index.to_string(),
+    }
+}
+
+// two variants converting from the same type would give overlapping impls, which the compiler
+// reports against the derive rather than the variants, so point at both of them here
+fn check_variant_duplicates(cx: &Context, variants: &[Variant<'_, FromField, FromVariant>]) {
+    let mut sources = Vec::new();
+    for v in variants.iter().filter(|v| !v.attrs.skip) {
+        let (from_type, _) = gen_info(&v.ident, &v.style, &v.fields);
+        sources.push((&v.ident, from_type.to_string(), None));
+        for ty in &v.attrs.types {
+            sources.push((&v.ident, ty.to_token_stream().to_string(), Some(ty)));
+        }
+    }
+    check_duplicates(cx, &sources);
+}
+
+// takes the variant or struct, the type it converts from and where that type was given in
+// `types`, `None` for the type of the fields. A duplicate given in `types` is reported there,
+// otherwise on every variant sharing the type
+fn check_duplicates(cx: &Context, sources: &[(&Ident, String, Option<&syn::Type>)]) {
+    for (i, (ident, from_type, extra)) in sources.iter().enumerate() {
+        let others: Vec<_> = sources
+            .iter()
+            .enumerate()
+            .filter(|(j, (_, other_type, other_extra))| {
+                i != *j && other_type == from_type && (extra.is_some() || other_extra.is_none())
+            })
+            .map(|(_, (other, _, _))| format!("`{}`", other))
+            .collect();
+        if others.is_empty() {
+            continue;
+        }
+        let from_type = from_type.replace(' ', "");
+        let others = others.join(", ");
+        match extra {
+            Some(ty) => cx.error_spanned_by(
+                ty,
+                format_args!(
+                    "`From<{}>` is also implemented for {}, remove it from `types`",
+                    from_type, others
+                ),
+            ),
+            None => cx.error_spanned_by(
+                ident,
+                format_args!(
+                    "`From<{}>` is also implemented for {}, use `#[from(skip)]` on all but one of them",
+                    from_type, others
+                ),
+            ),
+        }
+    }
+}
+
+fn gen_info(
     constructor: &Ident,
     style: &Style,
-    fields: &[Field<'_, F>],
+    fields: &[Field<'_, FromField>],
 ) -> (proc_macro2::TokenStream, proc_macro2::TokenStream) {
-    match style {
-        Style::Unit => (quote! {()}, quote! {#constructor}),
-        Style::Newtype => {
-            let field = &fields[0];
+    if let Style::Unit = style {
+        return (quote! {()}, quote! {#constructor});
+    }
+
+    // skipped fields are left out of the type to convert from and filled in with their default
+    let sources: Vec<_> = fields
+        .iter()
+        .filter(|f| f.attrs.default.is_none())
+        .collect();
+    let from_type = match sources.as_slice() {
+        [field] => {
             let ty = field.ty;
-            (quote! {#ty}, quote! {#constructor(value)})
+            quote! {#ty}
         }
-        Style::Tuple => {
-            if fields.len() == 1 {
-                let field = &fields[0];
-                let ty = field.ty;
-                (quote! {#ty}, quote! {#constructor(value)})
-            } else {
-                let recurse = fields.iter().map(|f| {
-                    let ty = &f.ty;
-                    quote_spanned! {f.original.span() => #ty}
-                });
-                let from_type = quote! { ( #(#recurse),* )};
-                let recurse = fields.iter().enumerate().map(|(i, f)| {
-                    let index = syn::Index::from(i);
-                    quote_spanned! {f.original.span() => value.#index}
-                });
-                let from_body = quote! { #constructor(#(#recurse),*) };
-                (from_type, from_body)
-            }
+        _ => {
+            let recurse = sources.iter().map(|f| {
+                let ty = &f.ty;
+                quote_spanned! {f.original.span() => #ty}
+            });
+            quote! { ( #(#recurse),* )}
         }
-        Style::Struct => {
-            if fields.len() == 1 {
-                let field = &fields[0];
-                let name = &field.original.ident;
-                let ty = field.ty;
-                (quote! {#ty}, quote! {#constructor { #name: value }})
-            } else {
-                let recurse = fields.iter().map(|f| {
-                    let ty = &f.ty;
-                    quote_spanned! {f.original.span() => #ty}
-                });
-                let from_type = quote! { ( #(#recurse),* )};
-                let recurse = fields.iter().enumerate().map(|(i, f)| {
-                    let index = syn::Index::from(i);
-                    let name = &f.original.ident;
-                    quote_spanned! {f.original.span() => #name: value.#index}
-                });
-                let from_body = quote! { #constructor { #(#recurse),* } };
+    };
 
-                (from_type, from_body)
+    let mut index = 0;
+    let values: Vec<_> = fields
+        .iter()
+        .map(|f| {
+            if f.attrs.default.is_some() {
+                return field_default(f);
             }
+            let value = if sources.len() == 1 {
+                quote! {value}
+            } else {
+                let index = syn::Index::from(index);
+                quote_spanned! {f.original.span() => value.#index}
+            };
+            index += 1;
+            value
+        })
+        .collect();
+
+    let from_body = match style {
+        Style::Struct => {
+            let recurse = fields.iter().zip(&values).map(|(f, value)| {
+                let name = &f.original.ident;
+                quote_spanned! {f.original.span() => #name: #value}
+            });
+            quote! { #constructor { #(#recurse),* } }
         }
-    }
+        _ => quote! { #constructor(#(#values),*) },
+    };
+
+    (from_type, from_body)
 }
@@ -63,6 +63,79 @@
 //! }
 //! ```
 //!
+//! for conversions that can fail, like narrowing integers, use the `try_into` attribute to take
+//! anything that is `TryInto` the field type instead, giving back the conversion error:
+//! ```text
+//! #[derive(Default, Builder)]
+//! struct Example {
+//!     #[builder(try_into)]
+//!     port: u16,
+//! }
+//!
+//! // will generate
+//! impl Example {
+//!     fn port<V: TryInto<u16>>(mut self, value: V) -> Result<Self, V::Error> {..}
+//! }
+//! ```
+//! `try_into` can't be combined with `no_into` or `validate`.
+//!
+//! setters for fields that don't need dropping, like `Copy` types, can be made `const fn` with the
+//! `const` attribute so values can be built up in a `const` or `static`. `Into` can't be called in
+//! a `const fn` so it needs `no_into` too:
+//! ```text
+//! #[derive(Builder)]
+//! struct Limits {
+//!     #[builder(const, no_into)]
+//!     max_len: usize,
+//! }
+//!
+//! // will generate
+//! impl Limits {
+//!     const fn max_len(mut self, value: usize) -> Self {..}
+//! }
+//!
+//! const LIMITS: Limits = Limits::new().max_len(10);
+//! ```
+//! `const` can't be combined with anything that calls other code (`try_into`, `validate`, `each`,
+//! `nested` or `setter(with = ..)`), with a `"mutable"` or `"immutable"` pattern or on the
+//! required fields of a `typestate` builder.
+//!
+//! when the natural argument isn't something that is `Into` the field use
+//! `setter(arg = "..", with = ..)` to give the type the setter takes and the function turning it
+//! into the field:
+//! ```text
+//! #[derive(Default, Builder)]
+//! struct Example {
+//!     #[builder(setter(arg = "u64", with = Duration::from_secs))]
+//!     timeout: Duration,
+//! }
+//!
+//! // will generate
+//! impl Example {
+//!     fn timeout(mut self, value: u64) -> Self {
+//!         self.timeout = Duration::from_secs(value);
+//!         self
+//!     }
+//! }
+//! ```
+//! with `strip_option` the function gives the inner value that gets wrapped in `Some`.
+//!
+//! the options for how a setter looks can all be grouped under `setter(..)`, which keeps fields
+//! with a lot of them readable. It takes `name`, `into`, `strip_option`, `vis` and `doc` along
+//! with `arg` and `with`:
+//! ```text
+//! #[derive(Default, Builder)]
+//! struct Example {
+//!     #[builder(setter(name = "label", into = false, doc = "Sets the label."))]
+//!     item: String,
+//!     #[builder(setter(strip_option(clear), vis = "pub(crate)"))]
+//!     value: Option<usize>,
+//! }
+//! ```
+//! `into = false` is the same as `no_into` and `name` the same as `rename`. The flat forms
+//! (`no_into`, `rename`, `strip_option`, `vis` and `doc` directly on the field) still work but
+//! are deprecated in favour of the group and may be removed in a future version.
+//!
 //! if you need to alter the names of the associated methods use `prefix` and/or `rename`
 //! attributes.
 //! ```text
@@ -81,7 +154,410 @@
 //! }
 //! ```
 //!
-//! The Builder pattern is not defined for enums, unit-like struct, newtypes, and tuple structs
+//! the setters have the same visibility as the type, use `vis` on the container or a field to
+//! change this or `private` to leave it off entirely:
+//! ```text
+//! #[derive(Default, Builder)]
+//! #[builder(vis = "pub(crate)")]
+//! pub struct Example {
+//!     item: String,
+//!     #[builder(private)]
+//!     secret: String,
+//! }
+//!
+//! // will generate
+//! impl Example {
+//!     pub(crate) fn item(mut self, ..) -> Self {..}
+//!     fn secret(mut self, ..) -> Self {..}
+//! }
+//! ```
+//! this only changes the setters, any generated builder type keeps the visibility of the type.
+//!
+//! the `///` docs of a field are copied onto its setter, or `doc` can be used to give it
+//! different docs. Fields without either and the other methods, like the ones from `each` or
+//! `strip_option(clear)`, get a short generated line. The builder types, errors and `build`
+//! functions are documented too so the generated code works with `#![deny(missing_docs)]`:
+//! ```text
+//! #[derive(Default, Builder)]
+//! pub struct Example {
+//!     /// the name of the example
+//!     pub item: String,
+//!     #[builder(doc = "Sets how many there are.")]
+//!     value: usize,
+//! }
+//! ```
+//!
+//! for a field whose type is a generic parameter the `change_type` attribute makes the setter
+//! take any type and give back the type with that parameter replaced, keeping every other field:
+//! ```text
+//! #[derive(Default, Builder)]
+//! struct Request<B> {
+//!     url: String,
+//!     #[builder(change_type)]
+//!     body: B,
+//! }
+//!
+//! // will generate
+//! impl<B> Request<B> {
+//!     fn url(mut self, ..) -> Self {..}
+//!     fn body<__B>(self, value: __B) -> Request<__B> {..}
+//! }
+//!
+//! // using the values
+//! fn func() {
+//!     let req: Request<Vec<u8>> = Request::<()>::default().body(vec![1, 2, 3]);
+//! }
+//! ```
+//! any bounds on the parameter have to hold for the new type too. The parameter can't be used by
+//! any other field and this works with the default and `separate` builders, where the builder
+//! type changes instead.
+//!
+//! getters can be generated along with the setters, for every field with `getters` on the
+//! container or for a single field with `get`. They return a reference unless `get(copy)` is
+//! used, and `get(mut)` adds a `_mut` version:
+//! ```text
+//! #[derive(Default, Builder)]
+//! #[builder(getters, prefix = "set_")]
+//! struct Example {
+//!     item: String,
+//!     #[builder(get(copy, mut))]
+//!     value: usize,
+//! }
+//!
+//! // will generate, along with the setters
+//! impl Example {
+//!     fn item(&self) -> &String {..}
+//!     fn value(&self) -> usize {..}
+//!     fn value_mut(&mut self) -> &mut usize {..}
+//! }
+//! ```
+//! getters are named after the field, so without a `separate` or `typestate` builder they clash
+//! with the setters, which is an error. Use `prefix`, `suffix` or `rename` to give the setters
+//! different names. Getters are not supported for enums.
+//!
+//! other attributes can be put on the setters with `setter_attr`, everything listed is copied
+//! onto the methods as is. On the container it applies to the setters of every field:
+//! ```text
+//! #[derive(Default, Builder)]
+//! #[builder(setter_attr(inline))]
+//! struct Example {
+//!     #[builder(setter_attr(must_use, deprecated(note = "use `value`")))]
+//!     item: String,
+//!     value: usize,
+//! }
+//!
+//! // will generate
+//! impl Example {
+//!     #[inline]
+//!     #[must_use]
+//!     #[deprecated(note = "use `value`")]
+//!     fn item(mut self, ..) -> Self {..}
+//!     #[inline]
+//!     fn value(mut self, ..) -> Self {..}
+//! }
+//! ```
+//!
+//! to change the case of every generated method name use `rename_all` with one of `lowercase`,
+//! `UPPERCASE`, `PascalCase`, `camelCase`, `snake_case` or `SCREAMING_SNAKE_CASE`. It is applied
+//! after the `prefix` and `suffix` but not to names given by `rename` or `each`:
+//! ```text
+//! #[derive(Builder)]
+//! #[builder(prefix = "set_", rename_all = "camelCase")]
+//! struct Example {
+//!     item_name: String,
+//! }
+//!
+//! // will generate
+//! impl Example {
+//!     fn setItemName(mut self, ..) -> Self {..}
+//! }
+//! ```
+//!
+//! if the type shouldn't need `Default` or you need to know when a field was never set use the
+//! `separate` attribute to generate a companion builder type instead:
+//! ```text
+//! #[derive(Builder)]
+//! #[builder(separate)]
+//! struct Example {
+//!     item: String,
+//!     value: usize,
+//! }
+//!
+//! // will generate
+//! struct ExampleBuilder {
+//!     item: Option<String>,
+//!     value: Option<usize>,
+//! }
+//!
+//! enum ExampleBuilderError {
+//!     MissingFields(Vec<&'static str>),
+//! }
+//!
+//! impl Example {
+//!     fn builder() -> ExampleBuilder {..}
+//! }
+//!
+//! impl ExampleBuilder {
+//!     fn item(mut self, ..) -> Self {..}
+//!     fn value(mut self, ..) -> Self {..}
+//!     fn build(self) -> Result<Example, ExampleBuilderError> {..}
+//! }
+//!
+//! // using the builder
+//! fn func() {
+//!     let ex = Example::builder()
+//!         .item("something")
+//!         .build(); // Err(MissingFields(vec!["value"]))
+//! }
+//! ```
+//! skipped fields are filled in with `Default::default()` when building.
+//!
+//! to make forgetting a field a compile error rather than a runtime one use `typestate` instead
+//! of `separate`. Each field becomes a type parameter on the builder that is `()` until the field
+//! is set and `build` only exists once every field has been set:
+//! ```text
+//! #[derive(Builder)]
+//! #[builder(typestate)]
+//! struct Example {
+//!     item: String,
+//!     value: usize,
+//! }
+//!
+//! // will generate
+//! struct ExampleBuilder<F0, F1> {
+//!     item: F0,
+//!     value: F1,
+//! }
+//!
+//! impl<F1> ExampleBuilder<(), F1> {
+//!     fn item(self, ..) -> ExampleBuilder<(String,), F1> {..}
+//! }
+//!
+//! impl<F0> ExampleBuilder<F0, ()> {
+//!     fn value(self, ..) -> ExampleBuilder<F0, (usize,)> {..}
+//! }
+//!
+//! impl ExampleBuilder<(String,), (usize,)> {
+//!     fn build(self) -> Example {..}
+//! }
+//!
+//! // using the builder
+//! fn func() {
+//!     let ex = Example::builder()
+//!         .item("something")
+//!         .build(); // error: no method named `build` found
+//! }
+//! ```
+//!
+//! with either `separate` or `typestate` a field can be made optional with the `default`
+//! attribute, either using `Default::default()` or the given expression:
+//! ```text
+//! #[derive(Builder)]
+//! #[builder(separate)]
+//! struct Example {
+//!     item: String,
+//!     #[builder(default)]
+//!     value: usize,
+//!     #[builder(default = "vec![1, 2, 3]")]
+//!     list: Vec<usize>,
+//! }
+//!
+//! // only `item` has to be set
+//! fn func() {
+//!     let ex = Example::builder()
+//!         .item("something")
+//!         .build(); // Ok(Example { item: "something", value: 0, list: [1, 2, 3] })
+//! }
+//! ```
+//! `default` can also be combined with `skip` to change what a skipped field is filled in with.
+//!
+//! for `Option<T>` fields the `strip_option` attribute makes the setter take `T` and wrap it in
+//! `Some`, with `strip_option(clear)` also generating a method to set it back to `None`. On the
+//! container it applies to every `Option` field.
+//! ```text
+//! #[derive(Default, Builder)]
+//! struct Example {
+//!     #[builder(strip_option(clear))]
+//!     timeout: Option<u64>,
+//! }
+//!
+//! // will generate
+//! impl Example {
+//!     fn timeout(mut self, value: impl Into<u64>) -> Self {..}
+//!     fn clear_timeout(mut self) -> Self {..}
+//! }
+//! ```
+//!
+//! for collections the `each` attribute adds a method for adding a single item along with an
+//! `extend_` method for adding many, both through `Extend`:
+//! ```text
+//! #[derive(Default, Builder)]
+//! struct Example {
+//!     #[builder(each = "arg")]
+//!     args: Vec<String>,
+//!     #[builder(each = "env")]
+//!     envs: HashMap<String, String>,
+//! }
+//!
+//! // will generate
+//! impl Example {
+//!     fn args(mut self, value: impl Into<Vec<String>>) -> Self {..}
+//!     fn arg(mut self, value: impl Into<String>) -> Self {..}
+//!     fn extend_args(mut self, items: impl IntoIterator<Item = impl Into<String>>) -> Self {..}
+//!     fn envs(mut self, value: impl Into<HashMap<String, String>>) -> Self {..}
+//!     fn env(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {..}
+//!     fn extend_envs(
+//!         mut self,
+//!         items: impl IntoIterator<Item = (impl Into<String>, impl Into<String>)>,
+//!     ) -> Self {..}
+//! }
+//! ```
+//! this works for `Vec`, `VecDeque`, `BinaryHeap`, `LinkedList` and types named like `*Set` or
+//! `*Map`. With `separate` or `typestate` builders these fields don't need to be set and start out
+//! empty.
+//!
+//! when a field is itself built up with setters the `nested` attribute adds an `_with` method that
+//! edits the current value through a closure, which takes `&mut` with the `"mutable"` pattern:
+//! ```text
+//! #[derive(Default, Builder)]
+//! struct Example {
+//!     #[builder(nested)]
+//!     inner: Inner,
+//! }
+//!
+//! // will generate
+//! impl Example {
+//!     fn inner(mut self, ..) -> Self {..}
+//!     fn inner_with(mut self, f: impl FnOnce(Inner) -> Inner) -> Self {..}
+//! }
+//!
+//! // using the values
+//! fn func() {
+//!     let ex = Example::default().inner_with(|inner| inner.name("something"));
+//! }
+//! ```
+//! for `separate` and `typestate` builders a field that hasn't been set yet starts out as its
+//! default.
+//!
+//! for tuple structs the methods are named after the index of the field, so `_0`, `_1`, etc.
+//! unless they are renamed, and for newtypes the method is `with_value`:
+//! ```text
+//! #[derive(Default, Builder)]
+//! struct Point(f32, #[builder(rename = "y")] f32);
+//!
+//! #[derive(Default, Builder)]
+//! struct Meters(f32);
+//!
+//! // will generate
+//! impl Point {
+//!     fn _0(mut self, ..) -> Self {..}
+//!     fn y(mut self, ..) -> Self {..}
+//! }
+//!
+//! impl Meters {
+//!     fn with_value(mut self, ..) -> Self {..}
+//! }
+//! ```
+//! with a `prefix` or `suffix` the newtype method is built from `value` instead, e.g.
+//! `set_value`. The `MissingFields` of a `separate` builder names these fields the same way, as
+//! `_0`, the renamed name or `value`.
+//!
+//! for enums each variant with fields gets its own builder, started from a snake case version of
+//! the variant name. With the default mode the fields start out as `Default::default()` (or
+//! their `default` attribute) and `build`, or `Into`, gives back the enum:
+//! ```text
+//! #[derive(Builder)]
+//! enum Shape {
+//!     Circle { radius: f32 },
+//!     RoundedRect { width: f32, height: f32 },
+//! }
+//!
+//! // will generate
+//! struct ShapeCircleBuilder {
+//!     radius: f32,
+//! }
+//!
+//! impl Shape {
+//!     fn circle() -> ShapeCircleBuilder {..}
+//!     fn rounded_rect() -> ShapeRoundedRectBuilder {..}
+//! }
+//!
+//! impl ShapeCircleBuilder {
+//!     fn radius(mut self, ..) -> Self {..}
+//!     fn build(self) -> Shape {..}
+//! }
+//! ...
+//!
+//! // using the builder
+//! fn func() {
+//!     let shape = Shape::circle().radius(2.0).build();
+//! }
+//! ```
+//! `separate` and `typestate` work the same way as for structs, with a single `ShapeBuilderError`
+//! shared by all the variants.
+//! Use `skip` on a variant to leave out its builder, like one holding types without `Default`.
+//!
+//! by default every setter takes and returns `self`, the `pattern` attribute changes this for the
+//! container or a single field. `"mutable"` setters take `&mut self` and return `&mut Self` which
+//! fits updating a value in place or in a loop, and `"immutable"` setters take `&self` and return
+//! an updated clone:
+//! ```text
+//! #[derive(Default, Builder)]
+//! #[builder(pattern = "mutable")]
+//! struct Example {
+//!     item: String,
+//!     #[builder(pattern = "immutable")]
+//!     value: usize,
+//! }
+//!
+//! // will generate
+//! impl Example {
+//!     fn item(&mut self, ..) -> &mut Self {..}
+//!     fn value(&self, ..) -> Self {..}
+//! }
+//! ```
+//! with a `"mutable"` container pattern the `build` of a `separate` builder, or of an enum
+//! variant, takes `&self` and clones the fields so the builder can be reused. `"immutable"` needs
+//! the type or builder to be `Clone`. Required fields of a `typestate` builder change the type of
+//! the builder so their setters have to take `self`, any other pattern on them, whether set on the
+//! field or the container, is an error.
+//!
+//! to check a value when it is set use the `validate` attribute with a function taking a
+//! reference to the value and returning a `Result<(), E>` where `E: Into<String>`. This replaces
+//! the setter with a `try_` version that gives back the error:
+//! ```text
+//! fn not_empty(value: &String) -> Result<(), &'static str> {..}
+//!
+//! #[derive(Default, Builder)]
+//! struct Example {
+//!     #[builder(validate = not_empty)]
+//!     item: String,
+//! }
+//!
+//! // will generate
+//! impl Example {
+//!     fn try_item(mut self, ..) -> Result<Self, String> {..}
+//! }
+//! ```
+//! with `strip_option` the function is given the value before it is wrapped in `Some`.
+//!
+//! on the container `validate` checks the whole value when building, so it needs a `separate` or
+//! `typestate` builder. For `separate` builders the error gets an `Invalid(String)` case and
+//! `typestate` builders have `build` return a `Result<Example, String>`:
+//! ```text
+//! #[derive(Builder)]
+//! #[builder(separate, validate = Range::check)]
+//! struct Range {
+//!     start: u32,
+//!     end: u32,
+//! }
+//!
+//! impl Range {
+//!     fn check(&self) -> Result<(), String> {..}
+//! }
+//! ```
+//!
+//! The Builder pattern is not defined for unit-like structs
 //!
 //! # From
 //! For the `From` derive it implements the trivial `From<Type>` implementations:
@@ -130,6 +606,32 @@
 //! }
 //! ```
 //!
+//! to convert from a single field of a struct instead mark it with `#[from]`, the other fields are
+//! filled in with `Default::default()` or the expression given by `default`:
+//! ```text
+//! #[derive(From)]
+//! struct ContextError {
+//!     #[from]
+//!     source: io::Error,
+//!     context: Vec<String>,
+//!     #[from(default = "\"unknown\"")]
+//!     path: &'static str,
+//! }
+//!
+//! // generates
+//! impl From<io::Error> for ContextError {
+//!     fn from(value: io::Error) -> Self {
+//!         ContextError {
+//!             source: value,
+//!             context: Default::default(),
+//!             path: "unknown",
+//!         }
+//!     }
+//! }
+//! ```
+//! each marked field gets its own implementation, replacing the one from the tuple of all the
+//! fields.
+//!
 //! If you need to not generate a `From` implementation use the `skip` attribute
 //! ```text
 //! #[derive(From)]
@@ -140,6 +642,79 @@
 //!     Pair(String, String),
 //! }
 //! ```
+//! this is needed when two variants hold the same type, as only one of them can have the `From`
+//! implementation and deriving for both is an error.
+//!
+//! fields can be left out of the type to convert from with `skip`, using `Default::default()`
+//! for them, or with `default` to give the expression to use:
+//! ```text
+//! #[derive(From)]
+//! struct Example<T> {
+//!     item: usize,
+//!     value: String,
+//!     #[from(default = "Vec::with_capacity(8)")]
+//!     cache: Vec<u8>,
+//!     #[from(skip)]
+//!     marker: PhantomData<T>,
+//! }
+//!
+//! // generates
+//! impl<T> From<(usize, String)> for Example<T> {
+//!     fn from(value: (usize, String)) -> Self {
+//!         Example {
+//!             item: value.0,
+//!             value: value.1,
+//!             cache: Vec::with_capacity(8),
+//!             marker: Default::default(),
+//!         }
+//!     }
+//! }
+//! ```
+//! with one field left it converts from that type and with none from `()`.
+//!
+//! a variant or struct with a single field can use `forward` to convert from anything that is
+//! `Into` the field type rather than just the type itself:
+//! ```text
+//! #[derive(From)]
+//! enum Error {
+//!     #[from(forward)]
+//!     Message(String),
+//!     Code(ErrorCode),
+//! }
+//!
+//! // will generate
+//! impl<T: Into<String>> From<T> for Error {
+//!     fn from(value: T) -> Self {
+//!         Error::Message(value.into())
+//!     }
+//! }
+//! impl From<ErrorCode> for Error {..}
+//! ```
+//! only one variant of an enum can use `forward`. The other variants can only convert from types
+//! the compiler can tell don't convert into the forwarded type, which means types from the same
+//! crate, or they have to be skipped. Variants holding primitives, tuples, generic parameters or
+//! the common `std` types are reported when deriving, and any other overlap is reported by the
+//! compiler on the variants involved.
+//!
+//! other types to convert from can be listed with `types`, these go through `Into` the type of
+//! the fields:
+//! ```text
+//! #[derive(From)]
+//! enum Value {
+//!     #[from(types(u8, u16, i32))]
+//!     Number(i64),
+//! }
+//!
+//! // will generate
+//! impl From<i64> for Value {..}
+//! impl From<u8> for Value {
+//!     fn from(value: u8) -> Self {
+//!         Value::Number(value.into())
+//!     }
+//! }
+//! impl From<u16> for Value {..}
+//! impl From<i32> for Value {..}
+//! ```
 //!
 //! # Features
 //! Default includes all available patterns, otherwise be more specific by using the `from` or
@@ -0,0 +1,50 @@
+use std::fmt::Debug;
+
+use boring_derive::Builder;
+
+#[derive(Debug, Default, PartialEq, Builder)]
+struct Request<B: Debug> {
+    url: String,
+    #[builder(change_type)]
+    body: B,
+}
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(separate)]
+struct Response<'a, T, B>
+where
+    B: Clone,
+{
+    status: &'a str,
+    extra: T,
+    #[builder(change_type)]
+    body: B,
+}
+
+fn main() {
+    let req: Request<Vec<u8>> = Request::<()>::default()
+        .url("localhost")
+        .body(vec![1u8, 2, 3]);
+    assert_eq!(
+        req,
+        Request {
+            url: "localhost".into(),
+            body: vec![1, 2, 3],
+        }
+    );
+
+    let res = Response::<u8, ()>::builder()
+        .status("ok")
+        .extra(1u8)
+        .body("text")
+        .build()
+        .unwrap();
+    assert_eq!(
+        res,
+        Response {
+            status: "ok",
+            extra: 1,
+            body: "text",
+        }
+    );
+}
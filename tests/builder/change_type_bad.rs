@@ -0,0 +1,23 @@
+use boring_derive::Builder;
+
+#[derive(Default, Builder)]
+struct Shared<B> {
+    #[builder(change_type)]
+    body: B,
+    backup: Option<B>,
+}
+
+#[derive(Default, Builder)]
+struct Concrete {
+    #[builder(change_type)]
+    body: Vec<u8>,
+}
+
+#[derive(Builder)]
+#[builder(typestate)]
+struct Typestate<B> {
+    #[builder(change_type, no_into)]
+    body: B,
+}
+
+fn main() {}
@@ -0,0 +1,67 @@
+use boring_derive::Builder;
+
+#[derive(Debug, PartialEq, Builder)]
+struct Limits {
+    #[builder(const, no_into)]
+    max_len: usize,
+    #[builder(const, no_into, strip_option(clear))]
+    timeout: Option<u64>,
+    #[builder(const, no_into, rename = "set_flag")]
+    flag: bool,
+}
+
+impl Limits {
+    const fn new() -> Self {
+        Limits {
+            max_len: 0,
+            timeout: None,
+            flag: false,
+        }
+    }
+}
+
+const LIMITS: Limits = Limits::new()
+    .max_len(10)
+    .timeout(30)
+    .clear_timeout()
+    .set_flag(true);
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(separate)]
+struct Point {
+    #[builder(const, no_into)]
+    x: i32,
+    #[builder(const, no_into, default)]
+    y: i32,
+}
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(typestate)]
+struct Size {
+    width: u32,
+    #[builder(const, no_into, default)]
+    height: u32,
+}
+
+fn main() {
+    assert_eq!(
+        LIMITS,
+        Limits {
+            max_len: 10,
+            timeout: None,
+            flag: true,
+        }
+    );
+
+    let point = Point::builder().x(1).y(2).build().unwrap();
+    assert_eq!(point, Point { x: 1, y: 2 });
+
+    let size = Size::builder().height(4).width(3u32).build();
+    assert_eq!(
+        size,
+        Size {
+            width: 3,
+            height: 4,
+        }
+    );
+}
@@ -0,0 +1,20 @@
+use boring_derive::Builder;
+
+#[derive(Default, Builder)]
+struct Example {
+    #[builder(const)]
+    into: usize,
+    #[builder(const, no_into, each = "arg")]
+    args: Vec<String>,
+    #[builder(const, no_into, pattern = "mutable")]
+    mutable: usize,
+}
+
+#[derive(Builder)]
+#[builder(typestate)]
+struct Size {
+    #[builder(const, no_into)]
+    width: u32,
+}
+
+fn main() {}
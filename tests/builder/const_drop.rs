@@ -0,0 +1,9 @@
+use boring_derive::Builder;
+
+#[derive(Default, Builder)]
+struct Example {
+    #[builder(const, no_into)]
+    name: String,
+}
+
+fn main() {}
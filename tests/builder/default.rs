@@ -0,0 +1,49 @@
+use boring_derive::Builder;
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(separate)]
+struct Separate {
+    item: String,
+    #[builder(default)]
+    value: usize,
+    #[builder(default = "vec![1, 2]")]
+    list: Vec<usize>,
+}
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(typestate)]
+struct Typestate {
+    item: String,
+    #[builder(default = "10")]
+    value: usize,
+    #[builder(skip, default = "String::from(\"cache\")")]
+    cache: String,
+}
+
+fn main() {
+    let ex = Separate::builder().item("something").build().unwrap();
+    assert_eq!(
+        ex,
+        Separate {
+            item: "something".into(),
+            value: 0,
+            list: vec![1, 2],
+        }
+    );
+
+    let err = Separate::builder().value(1usize).build().unwrap_err();
+    assert_eq!(err, SeparateBuilderError::MissingFields(vec!["item"]));
+
+    let ex = Typestate::builder().item("something").build();
+    assert_eq!(
+        ex,
+        Typestate {
+            item: "something".into(),
+            value: 10,
+            cache: "cache".into(),
+        }
+    );
+
+    let ex = Typestate::builder().value(1usize).item("something").build();
+    assert_eq!(ex.value, 1);
+}
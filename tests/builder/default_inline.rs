@@ -0,0 +1,9 @@
+use boring_derive::Builder;
+
+#[derive(Debug, Default, Builder)]
+struct Example {
+    #[builder(default = "1")]
+    item: usize,
+}
+
+fn main() {}
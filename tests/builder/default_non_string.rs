@@ -0,0 +1,10 @@
+use boring_derive::Builder;
+
+#[derive(Debug, Builder)]
+#[builder(separate)]
+struct Example {
+    #[builder(default = 1)]
+    item: usize,
+}
+
+fn main() {}
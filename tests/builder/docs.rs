@@ -0,0 +1,70 @@
+#![deny(missing_docs)]
+//! the generated items should all be documented
+
+use std::collections::HashMap;
+
+use boring_derive::Builder;
+
+/// an inline builder
+#[derive(Default, Builder)]
+pub struct Example {
+    /// the name of the thing
+    pub item: String,
+    #[builder(doc = "Sets how many there are.")]
+    value: usize,
+    /// extra arguments
+    #[builder(each = "arg")]
+    pub args: Vec<String>,
+    #[builder(strip_option(clear), nested)]
+    timeout: Option<u64>,
+    #[builder(try_into)]
+    port: u16,
+}
+
+/// a separate builder
+#[derive(Builder)]
+#[builder(separate, validate = Config::check)]
+pub struct Config {
+    /// the name of the thing
+    pub name: String,
+    #[builder(default, each = "env")]
+    envs: HashMap<String, String>,
+}
+
+impl Config {
+    fn check(&self) -> Result<(), String> {
+        Ok(())
+    }
+}
+
+/// a typestate builder
+#[derive(Builder)]
+#[builder(typestate)]
+pub struct Request {
+    /// where to send it
+    pub url: String,
+    #[builder(nested)]
+    headers: Vec<String>,
+}
+
+/// an enum
+#[derive(Builder)]
+pub enum Shape {
+    /// a circle
+    Circle {
+        /// how big it is
+        radius: f32,
+    },
+    /// a point
+    Point(f32, f32),
+}
+
+/// an enum with a separate builder
+#[derive(Builder)]
+#[builder(separate)]
+pub enum Message {
+    /// some text
+    Text(String),
+}
+
+fn main() {}
@@ -0,0 +1,54 @@
+use std::collections::{BTreeMap, HashSet};
+
+use boring_derive::Builder;
+
+#[derive(Debug, Default, PartialEq, Builder)]
+struct Inline {
+    #[builder(each = "arg")]
+    args: Vec<String>,
+    #[builder(each = "env")]
+    envs: BTreeMap<String, String>,
+}
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(separate)]
+struct Separate {
+    name: String,
+    #[builder(each = "tag")]
+    tags: HashSet<String>,
+    #[builder(each = "value", default = "vec![0]")]
+    values: Vec<usize>,
+}
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(typestate)]
+struct Typestate {
+    #[builder(each = "arg")]
+    args: Vec<String>,
+}
+
+fn main() {
+    let ex = Inline::default()
+        .arg("a")
+        .extend_args(["b", "c"])
+        .env("KEY", "value")
+        .extend_envs([("OTHER", "other")]);
+    assert_eq!(ex.args, vec!["a", "b", "c"]);
+    assert_eq!(ex.envs.len(), 2);
+    assert_eq!(ex.envs["KEY"], "value");
+
+    let ex = Separate::builder()
+        .name("name")
+        .tag("a")
+        .tag("a")
+        .value(1usize)
+        .build()
+        .unwrap();
+    assert_eq!(ex.tags.len(), 1);
+    assert_eq!(ex.values, vec![0, 1]);
+
+    let ex = Typestate::builder().build();
+    assert!(ex.args.is_empty());
+    let ex = Typestate::builder().arg("a").arg("b").build();
+    assert_eq!(ex.args, vec!["a", "b"]);
+}
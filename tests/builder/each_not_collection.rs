@@ -0,0 +1,13 @@
+use boring_derive::Builder;
+
+#[derive(Debug, Default, Builder)]
+struct Example {
+    #[builder(each = "arg")]
+    args: String,
+    #[builder(each = "item")]
+    items: Option<String>,
+    #[builder(each = "my-arg")]
+    more: Vec<String>,
+}
+
+fn main() {}
@@ -1,8 +1,68 @@
 use boring_derive::Builder;
 
-#[derive(Builder)]
-enum Example {
-    Item(usize),
+#[derive(Debug, PartialEq)]
+struct NoDefault;
+
+#[derive(Debug, PartialEq, Builder)]
+enum Shape {
+    Empty,
+    #[builder(skip)]
+    Raw(NoDefault),
+    Circle {
+        radius: f32,
+        #[builder(default = "1.0")]
+        scale: f32,
+    },
+    RoundedRect {
+        width: f32,
+        height: f32,
+        corner: f32,
+    },
+    Point(f32, f32),
+}
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(separate)]
+enum Separate {
+    Circle { radius: f32 },
+}
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(typestate)]
+enum Typestate {
+    Circle { radius: f32 },
 }
 
-fn main() {}
+fn main() {
+    let shape = Shape::circle().radius(2.0).build();
+    assert_eq!(
+        shape,
+        Shape::Circle {
+            radius: 2.0,
+            scale: 1.0
+        }
+    );
+
+    let shape: Shape = Shape::rounded_rect().width(1.0).height(2.0).into();
+    assert_eq!(
+        shape,
+        Shape::RoundedRect {
+            width: 1.0,
+            height: 2.0,
+            corner: 0.0
+        }
+    );
+
+    let shape = Shape::point()._1(1.0).build();
+    assert_eq!(shape, Shape::Point(0.0, 1.0));
+    assert_ne!(shape, Shape::Empty);
+
+    let shape = Separate::circle().build();
+    assert_eq!(
+        shape,
+        Err(SeparateBuilderError::MissingFields(vec!["radius"]))
+    );
+
+    let shape = Typestate::circle().radius(2.0).build();
+    assert_eq!(shape, Typestate::Circle { radius: 2.0 });
+}
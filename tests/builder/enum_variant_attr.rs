@@ -0,0 +1,11 @@
+use boring_derive::Builder;
+
+#[derive(Debug, Builder)]
+enum Shape {
+    #[builder(skip)]
+    Circle { radius: f32 },
+    #[builder(whatever = "x")]
+    Square { side: f32 },
+}
+
+fn main() {}
@@ -0,0 +1,46 @@
+use boring_derive::Builder;
+
+#[derive(Debug, Default, PartialEq, Builder)]
+#[builder(getters, prefix = "set_")]
+struct Example {
+    item: String,
+    #[builder(get(copy, mut))]
+    value: usize,
+}
+
+#[derive(Debug, Default, PartialEq, Builder)]
+struct Partial {
+    #[builder(get, rename = "with_name")]
+    name: String,
+    #[builder(skip, get(copy))]
+    id: u32,
+}
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(separate, getters)]
+struct Config {
+    name: String,
+}
+
+#[derive(Debug, Default, PartialEq, Builder)]
+#[builder(getters, prefix = "with_", rename_all = "camelCase")]
+struct Point(i32, #[builder(get(copy))] i32);
+
+fn main() {
+    let mut ex = Example::default().set_item("something").set_value(1usize);
+    assert_eq!(ex.item(), "something");
+    assert_eq!(ex.value(), 1);
+    *ex.value_mut() += 1;
+    assert_eq!(ex.value(), 2);
+
+    let partial = Partial::default().with_name("a");
+    assert_eq!(partial.name(), "a");
+    assert_eq!(partial.id(), 0);
+
+    let config = Config::builder().name("server").build().unwrap();
+    assert_eq!(config.name(), "server");
+
+    let point = Point::default().with0(1).with1(2);
+    assert_eq!(*point._0(), 1);
+    assert_eq!(point._1(), 2);
+}
@@ -0,0 +1,19 @@
+use boring_derive::Builder;
+
+#[derive(Default, Builder)]
+#[builder(getters)]
+struct Example {
+    item: String,
+    #[builder(rename = "set_value")]
+    value: usize,
+}
+
+#[derive(Builder)]
+enum Shape {
+    Circle {
+        #[builder(get)]
+        radius: f32,
+    },
+}
+
+fn main() {}
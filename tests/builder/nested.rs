@@ -0,0 +1,69 @@
+use boring_derive::Builder;
+
+#[derive(Debug, Default, Clone, PartialEq, Builder)]
+struct Inner {
+    name: String,
+    size: usize,
+}
+
+#[derive(Debug, Default, PartialEq, Builder)]
+struct Outer {
+    #[builder(nested)]
+    inner: Inner,
+    #[builder(nested, pattern = "mutable")]
+    other: Inner,
+}
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(separate)]
+struct Config {
+    #[builder(nested)]
+    inner: Inner,
+}
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(typestate)]
+struct Request {
+    #[builder(nested)]
+    inner: Inner,
+    #[builder(nested, default)]
+    extra: Inner,
+}
+
+fn main() {
+    let mut outer = Outer::default().inner_with(|inner| inner.name("a").size(1usize));
+    outer.other_with(|other| other.size = 2);
+    assert_eq!(
+        outer,
+        Outer {
+            inner: Inner {
+                name: "a".into(),
+                size: 1,
+            },
+            other: Inner {
+                name: String::new(),
+                size: 2,
+            },
+        }
+    );
+
+    let config = Config::builder()
+        .inner_with(|inner| inner.name("b"))
+        .inner_with(|inner| inner.size(3usize))
+        .build()
+        .unwrap();
+    assert_eq!(
+        config.inner,
+        Inner {
+            name: "b".into(),
+            size: 3,
+        }
+    );
+
+    let req = Request::builder()
+        .extra_with(|extra| extra.size(5usize))
+        .inner_with(|inner| inner.name("c"))
+        .build();
+    assert_eq!(req.inner.name, "c");
+    assert_eq!(req.extra.size, 5);
+}
@@ -1,6 +1,23 @@
 use boring_derive::Builder;
 
-#[derive(Builder)]
+#[derive(Debug, Default, PartialEq, Builder)]
 struct Example(usize);
 
-fn main() {}
+#[derive(Debug, Default, PartialEq, Builder)]
+#[builder(prefix = "set_")]
+struct Prefixed(usize);
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(separate)]
+struct Separate(usize);
+
+fn main() {
+    let ex = Example::default().with_value(1usize);
+    assert_eq!(ex, Example(1));
+
+    let ex = Prefixed::default().set_value(1usize);
+    assert_eq!(ex, Prefixed(1));
+
+    let ex = Separate::builder().build();
+    assert_eq!(ex, Err(SeparateBuilderError::MissingFields(vec!["value"])));
+}
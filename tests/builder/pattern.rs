@@ -0,0 +1,85 @@
+use boring_derive::Builder;
+
+#[derive(Debug, Default, Clone, PartialEq, Builder)]
+#[builder(pattern = "mutable")]
+struct Example {
+    item: String,
+    #[builder(each = "arg")]
+    args: Vec<String>,
+    #[builder(pattern = "immutable")]
+    value: usize,
+    #[builder(pattern = "owned")]
+    flag: bool,
+}
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(separate, pattern = "mutable")]
+struct Config {
+    name: String,
+    #[builder(default)]
+    retries: u32,
+}
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(separate, pattern = "immutable")]
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(typestate)]
+struct Request {
+    url: String,
+    #[builder(default, pattern = "mutable")]
+    timeout: u64,
+}
+
+fn main() {
+    let mut ex = Example::default();
+    ex.item("something");
+    for arg in ["a", "b"] {
+        ex.arg(arg);
+    }
+    let copy = ex.value(1usize);
+    let ex = ex.flag(true);
+    assert_eq!(
+        ex,
+        Example {
+            item: "something".into(),
+            args: vec!["a".into(), "b".into()],
+            value: 0,
+            flag: true,
+        }
+    );
+    assert_eq!(copy.value, 1);
+
+    let mut builder = Config::builder();
+    builder.name("server").retries(3u32);
+    assert_eq!(
+        builder.build(),
+        Ok(Config {
+            name: "server".into(),
+            retries: 3,
+        })
+    );
+    builder.retries(5u32);
+    assert_eq!(builder.build().unwrap().retries, 5);
+
+    let base = Point::builder().x(1);
+    let a = base.y(2).build().unwrap();
+    let b = base.y(3).build().unwrap();
+    assert_eq!(a, Point { x: 1, y: 2 });
+    assert_eq!(b, Point { x: 1, y: 3 });
+
+    let mut builder = Request::builder();
+    builder.timeout(30u64);
+    let req = builder.url("localhost").build();
+    assert_eq!(
+        req,
+        Request {
+            url: "localhost".into(),
+            timeout: 30,
+        }
+    );
+}
@@ -0,0 +1,24 @@
+use boring_derive::Builder;
+
+#[derive(Builder)]
+#[builder(pattern = "borrowed")]
+struct Example {
+    item: String,
+}
+
+#[derive(Builder)]
+#[builder(typestate)]
+struct Request {
+    #[builder(pattern = "mutable")]
+    url: String,
+}
+
+#[derive(Builder)]
+#[builder(typestate, pattern = "mutable")]
+struct Other {
+    name: String,
+    #[builder(default)]
+    extra: usize,
+}
+
+fn main() {}
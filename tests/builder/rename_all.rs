@@ -0,0 +1,58 @@
+use boring_derive::Builder;
+
+#[derive(Debug, Default, PartialEq, Builder)]
+#[builder(rename_all = "camelCase", prefix = "set_")]
+struct Example {
+    item_name: String,
+    #[builder(strip_option(clear))]
+    max_size: Option<usize>,
+    #[builder(each = "push_arg")]
+    extra_args: Vec<String>,
+    #[builder(rename = "raw_value")]
+    value: u8,
+}
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(separate, rename_all = "PascalCase")]
+struct Config {
+    server_name: String,
+}
+
+#[derive(Debug, Default, PartialEq, Builder)]
+#[builder(rename_all = "SCREAMING_SNAKE_CASE")]
+struct Point(i32, #[builder(rename = "y")] i32);
+
+#[derive(Debug, Default, PartialEq, Builder)]
+#[builder(rename_all = "SCREAMING_SNAKE_CASE", suffix = "_value")]
+struct Limits {
+    max_len: usize,
+}
+
+fn main() {
+    let ex = Example::default()
+        .setItemName("something")
+        .setMaxSize(2usize)
+        .clearMaxSize()
+        .setExtraArgs(vec!["a".to_string()])
+        .push_arg("b")
+        .extendExtraArgs(["c"])
+        .raw_value(1u8);
+    assert_eq!(
+        ex,
+        Example {
+            item_name: "something".into(),
+            max_size: None,
+            extra_args: vec!["a".into(), "b".into(), "c".into()],
+            value: 1,
+        }
+    );
+
+    let config = Config::builder().ServerName("server").build().unwrap();
+    assert_eq!(config.server_name, "server");
+
+    let point = Point::default()._0(1).y(2);
+    assert_eq!(point, Point(1, 2));
+
+    let limits = Limits::default().MAX_LEN_VALUE(3usize);
+    assert_eq!(limits, Limits { max_len: 3 });
+}
@@ -0,0 +1,9 @@
+use boring_derive::Builder;
+
+#[derive(Default, Builder)]
+#[builder(rename_all = "kebab-case")]
+struct Example {
+    item: String,
+}
+
+fn main() {}
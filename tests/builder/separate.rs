@@ -0,0 +1,30 @@
+use boring_derive::Builder;
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(separate, prefix = "with_")]
+struct Example<T> {
+    item: String,
+    value: T,
+    #[builder(skip)]
+    cache: Vec<usize>,
+}
+
+fn main() {
+    let ex = Example::builder()
+        .with_item("something")
+        .with_value(1)
+        .build()
+        .unwrap();
+    assert_eq!(
+        ex,
+        Example {
+            item: "something".into(),
+            value: 1,
+            cache: Vec::new(),
+        }
+    );
+
+    let err = Example::<usize>::builder().with_item("something").build().unwrap_err();
+    assert_eq!(err, ExampleBuilderError::MissingFields(vec!["value"]));
+    assert_eq!(err.to_string(), "missing fields for `Example`: `value`");
+}
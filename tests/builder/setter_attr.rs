@@ -0,0 +1,35 @@
+#![deny(unused_must_use)]
+
+use boring_derive::Builder;
+
+#[derive(Debug, Default, PartialEq, Builder)]
+#[builder(setter_attr(inline))]
+struct Example {
+    #[builder(setter_attr(must_use = "setters return the updated value", track_caller))]
+    item: String,
+    #[builder(setter_attr(deprecated(note = "use `item`")))]
+    name: String,
+    #[builder(setter_attr(cfg(any())))]
+    hidden: bool,
+}
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(typestate, setter_attr(must_use))]
+struct Request {
+    url: String,
+}
+
+#[allow(deprecated)]
+fn main() {
+    let ex = Example::default().item("something").name("other");
+    assert_eq!(
+        ex,
+        Example {
+            item: "something".into(),
+            name: "other".into(),
+            hidden: false,
+        }
+    );
+    let req = Request::builder().url("localhost").build();
+    assert_eq!(req.url, "localhost");
+}
@@ -0,0 +1,17 @@
+#![deny(unused_must_use, deprecated)]
+
+use boring_derive::Builder;
+
+#[derive(Default, Builder)]
+struct Example {
+    #[builder(setter_attr(must_use))]
+    item: String,
+    #[builder(setter_attr(deprecated(note = "use `item`")))]
+    name: String,
+}
+
+fn main() {
+    let ex = Example::default();
+    ex.item("something");
+    let _ = Example::default().name("other");
+}
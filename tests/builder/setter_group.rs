@@ -0,0 +1,28 @@
+use boring_derive::Builder;
+
+#[derive(Debug, Default, PartialEq, Builder)]
+#[builder(prefix = "set_")]
+pub struct Example {
+    #[builder(setter(name = "label", into = false, doc = "Sets the label."))]
+    item: String,
+    #[builder(setter(into, strip_option(clear), vis = "pub(crate)"))]
+    value: Option<usize>,
+    #[builder(setter(into = true))]
+    count: u64,
+}
+
+fn main() {
+    let ex = Example::default()
+        .label(String::from("name"))
+        .set_value(3usize)
+        .set_count(5u32);
+    assert_eq!(
+        ex,
+        Example {
+            item: String::from("name"),
+            value: Some(3),
+            count: 5,
+        }
+    );
+    assert_eq!(ex.clear_value().value, None);
+}
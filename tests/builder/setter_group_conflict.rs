@@ -0,0 +1,13 @@
+use boring_derive::Builder;
+
+#[derive(Default, Builder)]
+struct Example {
+    #[builder(no_into, setter(into))]
+    item: String,
+    #[builder(setter(into = "no"))]
+    value: usize,
+    #[builder(setter(prefix = "set_"))]
+    other: usize,
+}
+
+fn main() {}
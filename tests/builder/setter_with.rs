@@ -0,0 +1,37 @@
+use std::path::PathBuf;
+use std::time::Duration;
+
+use boring_derive::Builder;
+
+fn absolute(path: &str) -> PathBuf {
+    PathBuf::from("/root").join(path)
+}
+
+#[derive(Debug, Default, PartialEq, Builder)]
+struct Example {
+    #[builder(setter(arg = "u64", with = Duration::from_secs))]
+    timeout: Duration,
+    #[builder(setter(arg = "&str", with = absolute), strip_option)]
+    path: Option<PathBuf>,
+}
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(typestate)]
+struct Request {
+    #[builder(setter(arg = "u64", with = Duration::from_millis))]
+    timeout: Duration,
+}
+
+fn main() {
+    let ex = Example::default().timeout(5).path("config");
+    assert_eq!(
+        ex,
+        Example {
+            timeout: Duration::from_secs(5),
+            path: Some(PathBuf::from("/root/config")),
+        }
+    );
+
+    let req = Request::builder().timeout(250).build();
+    assert_eq!(req.timeout, Duration::from_millis(250));
+}
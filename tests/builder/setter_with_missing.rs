@@ -0,0 +1,13 @@
+use boring_derive::Builder;
+
+#[derive(Default, Builder)]
+struct Example {
+    #[builder(setter(arg = "u64"))]
+    first: u32,
+    #[builder(setter(with = u32::from))]
+    second: u32,
+    #[builder(setter(arg = u64, with = u32::from))]
+    third: u32,
+}
+
+fn main() {}
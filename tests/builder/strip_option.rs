@@ -0,0 +1,41 @@
+use boring_derive::Builder;
+
+#[derive(Debug, Default, PartialEq, Builder)]
+struct Inline {
+    #[builder(strip_option(clear))]
+    timeout: Option<u64>,
+    name: Option<String>,
+}
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(separate, strip_option)]
+struct Separate {
+    name: Option<String>,
+    count: usize,
+}
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(typestate, strip_option(clear))]
+struct Typestate {
+    name: Option<String>,
+}
+
+fn main() {
+    let ex = Inline::default().timeout(5u64).name(Some("name".to_string()));
+    assert_eq!(ex.timeout, Some(5));
+    assert_eq!(ex.clear_timeout().timeout, None);
+
+    let ex = Separate::builder().name("name").count(1usize).build().unwrap();
+    assert_eq!(
+        ex,
+        Separate {
+            name: Some("name".into()),
+            count: 1,
+        }
+    );
+
+    let ex = Typestate::builder().name("name").build();
+    assert_eq!(ex.name, Some("name".into()));
+    let ex = Typestate::builder().clear_name().build();
+    assert_eq!(ex.name, None);
+}
@@ -0,0 +1,9 @@
+use boring_derive::Builder;
+
+#[derive(Debug, Default, Builder)]
+struct Example {
+    #[builder(strip_option)]
+    timeout: u64,
+}
+
+fn main() {}
@@ -0,0 +1,39 @@
+use std::num::TryFromIntError;
+
+use boring_derive::Builder;
+
+#[derive(Debug, Default, PartialEq, Builder)]
+struct Example {
+    #[builder(try_into)]
+    port: u16,
+    #[builder(try_into, strip_option)]
+    retries: Option<u8>,
+}
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(typestate)]
+struct Request {
+    #[builder(try_into)]
+    port: u16,
+}
+
+fn main() {
+    let ex = Example::default()
+        .port(8080u64)
+        .unwrap()
+        .retries(3i32)
+        .unwrap();
+    assert_eq!(
+        ex,
+        Example {
+            port: 8080,
+            retries: Some(3),
+        }
+    );
+    let err: TryFromIntError = Example::default().port(70000u64).unwrap_err();
+    assert_eq!(err.to_string(), "out of range integral type conversion attempted");
+    assert!(Example::default().retries(-1i32).is_err());
+
+    let req = Request::builder().port(443u32).unwrap().build();
+    assert_eq!(req, Request { port: 443 });
+}
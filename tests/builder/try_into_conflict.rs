@@ -0,0 +1,15 @@
+use boring_derive::Builder;
+
+fn check(_: &u16) -> Result<(), String> {
+    Ok(())
+}
+
+#[derive(Default, Builder)]
+struct Example {
+    #[builder(try_into, no_into)]
+    port: u16,
+    #[builder(try_into, validate = check)]
+    other: u16,
+}
+
+fn main() {}
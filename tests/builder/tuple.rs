@@ -1,6 +1,23 @@
 use boring_derive::Builder;
 
-#[derive(Builder)]
-struct Example(usize, String);
+#[derive(Debug, Default, PartialEq, Builder)]
+struct Example(usize, #[builder(rename = "name")] String);
 
-fn main() {}
+#[derive(Debug, PartialEq, Builder)]
+#[builder(separate)]
+struct Separate(f32, f32, #[builder(rename = "y")] f32);
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(typestate)]
+struct Typestate(f32, #[builder(default)] f32);
+
+fn main() {
+    let ex = Example::default()._0(1usize).name("something");
+    assert_eq!(ex, Example(1, "something".into()));
+
+    let ex = Separate::builder()._0(1.0).build();
+    assert_eq!(ex, Err(SeparateBuilderError::MissingFields(vec!["_1", "y"])));
+
+    let ex = Typestate::builder()._0(1.0).build();
+    assert_eq!(ex, Typestate(1.0, 0.0));
+}
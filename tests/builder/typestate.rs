@@ -0,0 +1,31 @@
+use boring_derive::Builder;
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(typestate)]
+struct Example<'a, T> {
+    item: &'a str,
+    value: T,
+    #[builder(skip)]
+    cache: Vec<usize>,
+}
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(typestate)]
+struct Defaulted<T = u8> {
+    value: T,
+}
+
+fn main() {
+    let ex = Example::builder().value(1).item("something").build();
+    assert_eq!(
+        ex,
+        Example {
+            item: "something",
+            value: 1,
+            cache: Vec::new(),
+        }
+    );
+
+    let defaulted: Defaulted = Defaulted::builder().value(3u8).build();
+    assert_eq!(defaulted, Defaulted { value: 3 });
+}
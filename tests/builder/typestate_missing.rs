@@ -0,0 +1,13 @@
+use boring_derive::Builder;
+
+#[derive(Debug, Builder)]
+#[builder(typestate)]
+struct Example {
+    item: String,
+    value: usize,
+}
+
+fn main() {
+    let ex = Example::builder().item("something").build();
+    println!("{:?}", ex);
+}
@@ -0,0 +1,9 @@
+use boring_derive::Builder;
+
+#[derive(Debug, Builder)]
+#[builder(separate, typestate)]
+struct Example {
+    item: String,
+}
+
+fn main() {}
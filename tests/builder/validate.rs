@@ -0,0 +1,105 @@
+use boring_derive::Builder;
+
+fn not_empty(value: &String) -> Result<(), &'static str> {
+    if value.is_empty() {
+        Err("name cannot be empty")
+    } else {
+        Ok(())
+    }
+}
+
+fn port(value: &u16) -> Result<(), String> {
+    if *value == 0 {
+        Err(format!("{} is not a valid port", value))
+    } else {
+        Ok(())
+    }
+}
+
+#[derive(Debug, Default, PartialEq, Builder)]
+struct Example {
+    #[builder(validate = not_empty)]
+    name: String,
+    #[builder(validate = port, strip_option)]
+    port: Option<u16>,
+}
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(separate, validate = Range::check)]
+struct Range {
+    start: u32,
+    end: u32,
+}
+
+impl Range {
+    fn check(&self) -> Result<(), String> {
+        if self.start <= self.end {
+            Ok(())
+        } else {
+            Err(format!("{} is after {}", self.start, self.end))
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(typestate, validate = Span::check)]
+struct Span {
+    #[builder(validate = not_empty)]
+    label: String,
+    len: usize,
+}
+
+impl Span {
+    fn check(&self) -> Result<(), &'static str> {
+        if self.len > 0 {
+            Ok(())
+        } else {
+            Err("empty span")
+        }
+    }
+}
+
+fn main() {
+    let ex = Example::default()
+        .try_name("something")
+        .unwrap()
+        .try_port(8080u16)
+        .unwrap();
+    assert_eq!(
+        ex,
+        Example {
+            name: "something".into(),
+            port: Some(8080),
+        }
+    );
+    assert_eq!(
+        Example::default().try_name("").unwrap_err(),
+        "name cannot be empty"
+    );
+    assert_eq!(
+        Example::default().try_port(0u16).unwrap_err(),
+        "0 is not a valid port"
+    );
+
+    let range = Range::builder().start(1u32).end(2u32).build();
+    assert_eq!(range, Ok(Range { start: 1, end: 2 }));
+    let err = Range::builder().start(3u32).end(2u32).build().unwrap_err();
+    assert_eq!(err, RangeBuilderError::Invalid("3 is after 2".into()));
+    assert_eq!(err.to_string(), "invalid `Range`: 3 is after 2");
+
+    let span = Span::builder().try_label("word").unwrap().len(4usize).build();
+    assert_eq!(
+        span,
+        Ok(Span {
+            label: "word".into(),
+            len: 4,
+        })
+    );
+    assert!(Span::builder().try_label("").is_err());
+    let err = Span::builder()
+        .try_label("word")
+        .unwrap()
+        .len(0usize)
+        .build();
+    assert_eq!(err, Err("empty span".to_string()));
+}
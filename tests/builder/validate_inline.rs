@@ -0,0 +1,13 @@
+use boring_derive::Builder;
+
+fn check(_: &Example) -> Result<(), String> {
+    Ok(())
+}
+
+#[derive(Default, Builder)]
+#[builder(validate = check)]
+struct Example {
+    item: String,
+}
+
+fn main() {}
@@ -0,0 +1,43 @@
+mod config {
+    use boring_derive::Builder;
+
+    #[derive(Debug, Default, PartialEq, Builder)]
+    pub struct Config {
+        pub name: String,
+        #[builder(vis = "pub(crate)")]
+        pub retries: u32,
+        #[builder(private)]
+        pub secret: String,
+    }
+
+    impl Config {
+        pub fn with_secret(self) -> Self {
+            self.secret("hidden")
+        }
+    }
+
+    #[derive(Debug, Default, PartialEq, Builder)]
+    #[builder(vis = "pub(crate)")]
+    pub struct Internal {
+        pub value: u32,
+        #[builder(vis = "pub")]
+        pub label: String,
+    }
+}
+
+use config::{Config, Internal};
+
+fn main() {
+    let config = Config::default().name("server").retries(3u32).with_secret();
+    assert_eq!(
+        config,
+        Config {
+            name: "server".into(),
+            retries: 3,
+            secret: "hidden".into(),
+        }
+    );
+
+    let internal = Internal::default().value(1u32).label("a");
+    assert_eq!(internal.value, 1);
+}
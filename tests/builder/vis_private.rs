@@ -0,0 +1,21 @@
+mod config {
+    use boring_derive::Builder;
+
+    #[derive(Default, Builder)]
+    pub struct Config {
+        pub name: String,
+        #[builder(private)]
+        pub secret: String,
+    }
+
+    #[derive(Default, Builder)]
+    #[builder(private)]
+    pub struct Internal {
+        pub value: u32,
+    }
+}
+
+fn main() {
+    let _ = config::Config::default().name("server").secret("oops");
+    let _ = config::Internal::default().value(1u32);
+}
@@ -0,0 +1,14 @@
+use boring_derive::From;
+
+#[derive(Debug, From)]
+enum Example {
+    Name(String),
+    Number(f32),
+    Label(String),
+    #[from(skip)]
+    Other(String),
+    Empty,
+    Nothing,
+}
+
+fn main() {}
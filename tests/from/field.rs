@@ -0,0 +1,47 @@
+use std::io;
+
+use boring_derive::From;
+
+#[derive(Debug, From)]
+struct ContextError {
+    #[from]
+    source: io::Error,
+    context: Vec<String>,
+    #[from(default = "\"unknown\"")]
+    path: &'static str,
+}
+
+#[derive(Debug, PartialEq, From)]
+struct Id(#[from] u32, Option<String>);
+
+#[derive(Debug, PartialEq, From)]
+struct Either {
+    #[from]
+    number: i64,
+    #[from]
+    text: String,
+}
+
+fn main() {
+    let err: ContextError = io::Error::new(io::ErrorKind::Other, "oops").into();
+    assert_eq!(err.source.to_string(), "oops");
+    assert!(err.context.is_empty());
+    assert_eq!(err.path, "unknown");
+
+    assert_eq!(Id::from(3), Id(3, None));
+
+    assert_eq!(
+        Either::from(2),
+        Either {
+            number: 2,
+            text: String::new()
+        }
+    );
+    assert_eq!(
+        Either::from("two".to_string()),
+        Either {
+            number: 0,
+            text: "two".to_string()
+        }
+    );
+}
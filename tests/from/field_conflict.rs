@@ -0,0 +1,24 @@
+use boring_derive::From;
+
+#[derive(Debug, From)]
+struct Both {
+    #[from]
+    first: String,
+    #[from]
+    second: String,
+}
+
+#[derive(Debug, From)]
+struct Skipped {
+    #[from]
+    #[from(skip)]
+    first: String,
+    second: String,
+}
+
+#[derive(Debug, From)]
+enum Variant {
+    Pair(#[from] String, usize),
+}
+
+fn main() {}
@@ -0,0 +1,57 @@
+use std::marker::PhantomData;
+
+use boring_derive::From;
+
+#[derive(Debug, PartialEq, From)]
+struct Cached<T> {
+    value: u32,
+    #[from(skip)]
+    cache: Option<u64>,
+    name: String,
+    #[from(skip)]
+    marker: PhantomData<T>,
+}
+
+#[derive(Debug, PartialEq, From)]
+struct Single(String, #[from(default = "3")] usize);
+
+#[derive(Debug, PartialEq, From)]
+struct Empty {
+    #[from(skip)]
+    items: Vec<u8>,
+}
+
+#[derive(Debug, PartialEq, From)]
+enum Value {
+    Pair(u8, #[from(skip)] u16, u32),
+    Named {
+        #[from(default = "\"none\"")]
+        label: &'static str,
+        count: usize,
+    },
+}
+
+fn main() {
+    let cached: Cached<()> = (1, "one".to_string()).into();
+    assert_eq!(
+        cached,
+        Cached {
+            value: 1,
+            cache: None,
+            name: "one".to_string(),
+            marker: PhantomData,
+        }
+    );
+
+    assert_eq!(Single::from("two".to_string()), Single("two".to_string(), 3));
+    assert_eq!(Empty::from(()), Empty { items: Vec::new() });
+
+    assert_eq!(Value::from((1u8, 2u32)), Value::Pair(1, 0, 2));
+    assert_eq!(
+        Value::from(5usize),
+        Value::Named {
+            label: "none",
+            count: 5
+        }
+    );
+}
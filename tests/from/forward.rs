@@ -0,0 +1,45 @@
+use std::borrow::Cow;
+
+use boring_derive::From;
+
+#[derive(Debug, PartialEq)]
+struct Code(u32);
+
+#[derive(Debug, PartialEq, From)]
+enum Error {
+    #[from(forward)]
+    Message(String),
+    Code(Code),
+    #[from(skip)]
+    Unknown,
+}
+
+#[derive(Debug, PartialEq, From)]
+#[from(forward)]
+struct Name {
+    inner: String,
+}
+
+#[derive(Debug, PartialEq, From)]
+#[from(forward)]
+struct Wrapper<T>(Vec<T>);
+
+fn main() {
+    assert_eq!(Error::from("bad"), Error::Message("bad".to_string()));
+    assert_eq!(
+        Error::from(Box::<str>::from("boxed")),
+        Error::Message("boxed".to_string())
+    );
+    assert_eq!(
+        Error::from(Cow::Borrowed("cow")),
+        Error::Message("cow".to_string())
+    );
+    assert_eq!(Error::from(Code(3)), Error::Code(Code(3)));
+    assert_ne!(Error::from("bad"), Error::Unknown);
+
+    let name: Name = "ferris".into();
+    assert_eq!(name.inner, "ferris");
+
+    let wrapper: Wrapper<u8> = [1u8, 2, 3].as_slice().into();
+    assert_eq!(wrapper.0, vec![1, 2, 3]);
+}
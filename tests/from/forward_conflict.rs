@@ -0,0 +1,27 @@
+use boring_derive::From;
+
+#[derive(Debug, From)]
+enum Error {
+    #[from(forward)]
+    Message(String),
+    #[from(forward)]
+    Path(std::path::PathBuf),
+    #[from(forward)]
+    Pair(String, String),
+    Code(i32),
+    #[from(types(u8))]
+    Local(Local),
+    #[from(skip)]
+    Skipped(u16),
+}
+
+#[derive(Debug)]
+struct Local;
+
+#[derive(Debug, From)]
+#[from(forward)]
+enum Other {
+    Message(String),
+}
+
+fn main() {}
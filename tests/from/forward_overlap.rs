@@ -0,0 +1,12 @@
+use std::io::Error;
+
+use boring_derive::From;
+
+#[derive(Debug, From)]
+enum Failure {
+    #[from(forward)]
+    Message(String),
+    Io(Error),
+}
+
+fn main() {}
@@ -0,0 +1,27 @@
+use boring_derive::From;
+
+#[derive(Debug, PartialEq, From)]
+enum Value {
+    #[from(types(u8, u16, i32))]
+    Number(i64),
+    #[from(types(&'static str, Box<str>))]
+    Text(String),
+}
+
+#[derive(Debug, PartialEq, From)]
+#[from(types(f32))]
+struct Ratio(f64);
+
+fn main() {
+    assert_eq!(Value::from(1u8), Value::Number(1));
+    assert_eq!(Value::from(2u16), Value::Number(2));
+    assert_eq!(Value::from(-3i32), Value::Number(-3));
+    assert_eq!(Value::from(4i64), Value::Number(4));
+    assert_eq!(Value::from("text"), Value::Text("text".to_string()));
+    assert_eq!(
+        Value::from(Box::<str>::from("boxed")),
+        Value::Text("boxed".to_string())
+    );
+
+    assert_eq!(Ratio::from(0.5f32), Ratio(0.5));
+}
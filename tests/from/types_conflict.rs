@@ -0,0 +1,18 @@
+use boring_derive::From;
+
+#[derive(Debug, From)]
+enum Value {
+    #[from(types(u8, i32))]
+    Number(i64),
+    Small(i32),
+    #[from(skip, types(u16))]
+    Other(u64),
+    #[from(forward, types(&'static str))]
+    Text(String),
+}
+
+#[derive(Debug, From)]
+#[from(types(i64, u8, u8))]
+struct Number(i64);
+
+fn main() {}
@@ -6,22 +6,68 @@ fn test() {
     t.pass("tests/builder/prefix.rs");
     t.pass("tests/builder/suffix.rs");
     t.pass("tests/builder/rename.rs");
+    t.pass("tests/builder/separate.rs");
+    t.pass("tests/builder/typestate.rs");
+    t.pass("tests/builder/default.rs");
+    t.pass("tests/builder/strip_option.rs");
+    t.pass("tests/builder/each.rs");
+    t.pass("tests/builder/tuple.rs");
+    t.pass("tests/builder/newtype.rs");
+    t.pass("tests/builder/enum.rs");
+    t.pass("tests/builder/pattern.rs");
+    t.pass("tests/builder/validate.rs");
+    t.pass("tests/builder/try_into.rs");
+    t.pass("tests/builder/nested.rs");
+    t.pass("tests/builder/setter_with.rs");
+    t.pass("tests/builder/setter_group.rs");
+    t.pass("tests/builder/rename_all.rs");
+    t.pass("tests/builder/vis.rs");
+    t.pass("tests/builder/docs.rs");
+    t.pass("tests/builder/setter_attr.rs");
+    t.pass("tests/builder/const.rs");
+    t.pass("tests/builder/getters.rs");
+    t.pass("tests/builder/change_type.rs");
     t.compile_fail("tests/builder/skip.rs");
     t.compile_fail("tests/builder/no_into.rs");
-    t.compile_fail("tests/builder/enum.rs");
-    t.compile_fail("tests/builder/newtype.rs");
-    t.compile_fail("tests/builder/tuple.rs");
     t.compile_fail("tests/builder/unit.rs");
     t.compile_fail("tests/builder/bad_attr.rs");
     t.compile_fail("tests/builder/prefix_non_string.rs");
     t.compile_fail("tests/builder/suffix_non_string.rs");
     t.compile_fail("tests/builder/rename_non_string.rs");
+    t.compile_fail("tests/builder/typestate_missing.rs");
+    t.compile_fail("tests/builder/typestate_separate.rs");
+    t.compile_fail("tests/builder/default_inline.rs");
+    t.compile_fail("tests/builder/default_non_string.rs");
+    t.compile_fail("tests/builder/strip_option_not_option.rs");
+    t.compile_fail("tests/builder/each_not_collection.rs");
+    t.compile_fail("tests/builder/pattern_bad.rs");
+    t.compile_fail("tests/builder/validate_inline.rs");
+    t.compile_fail("tests/builder/try_into_conflict.rs");
+    t.compile_fail("tests/builder/setter_with_missing.rs");
+    t.compile_fail("tests/builder/setter_group_conflict.rs");
+    t.compile_fail("tests/builder/rename_all_unknown.rs");
+    t.compile_fail("tests/builder/vis_private.rs");
+    t.compile_fail("tests/builder/setter_attr_must_use.rs");
+    t.compile_fail("tests/builder/const_conflict.rs");
+    t.compile_fail("tests/builder/const_drop.rs");
+    t.compile_fail("tests/builder/enum_variant_attr.rs");
+    t.compile_fail("tests/builder/getters_collision.rs");
+    t.compile_fail("tests/builder/change_type_bad.rs");
     // from
     t.pass("tests/from/enum.rs");
     t.pass("tests/from/struct.rs");
     t.pass("tests/from/newtype.rs");
     t.pass("tests/from/unit.rs");
     t.pass("tests/from/tuple.rs");
+    t.pass("tests/from/forward.rs");
+    t.pass("tests/from/types.rs");
+    t.pass("tests/from/field.rs");
+    t.pass("tests/from/field_skip.rs");
     t.compile_fail("tests/from/bad_attr.rs");
     t.compile_fail("tests/from/skip.rs");
+    t.compile_fail("tests/from/duplicate.rs");
+    t.compile_fail("tests/from/forward_conflict.rs");
+    t.compile_fail("tests/from/forward_overlap.rs");
+    t.compile_fail("tests/from/types_conflict.rs");
+    t.compile_fail("tests/from/field_conflict.rs");
 }